@@ -0,0 +1,218 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Ident, Member};
+
+/// `snake_case` to `CamelCase`, for the variants of the error type
+fn camel_case(ident: &Ident) -> syn::Result<Ident> {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    let mut camel = String::new();
+
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        camel.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        camel.extend(chars);
+    }
+
+    // names like `_` or `_0` don't have a `CamelCase` form which is an identifier
+    match syn::parse_str::<Ident>(&camel) {
+        Ok(camel) => Ok(Ident::new(&camel.to_string(), ident.span())),
+        Err(_) => Err(Error::new_spanned(
+            ident,
+            format!("`Init` can't name the error variant for `{name}`, rename the field"),
+        )),
+    }
+}
+
+/// the error variant for each field, which must all be distinct
+fn variants(fields: &Fields) -> syn::Result<Vec<Ident>> {
+    let mut variants = Vec::<Ident>::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let variant = match &field.ident {
+            Some(ident) => camel_case(ident)?,
+            None => format_ident!("Field{}", i),
+        };
+
+        if variants.contains(&variant) {
+            return Err(Error::new_spanned(
+                &field.ident,
+                format!(
+                    "the error variant `{variant}` for this field is already used by another field"
+                ),
+            ));
+        }
+
+        variants.push(variant);
+    }
+
+    Ok(variants)
+}
+
+fn check_not_packed(input: &DeriveInput) -> syn::Result<()> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                return Err(meta.error("`Init` can't be derived for packed structs"));
+            }
+
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`Init` can only be derived for structs",
+            ))
+        }
+    };
+
+    check_not_packed(&input)?;
+
+    let core = quote!(::ip_init::macros::core);
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let builder = format_ident!("{}Init", name);
+    let error = format_ident!("{}InitError", name);
+
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        })
+        .collect::<Vec<_>>();
+    let inits = (0..fields.len())
+        .map(|i| format_ident!("__I{}", i))
+        .collect::<Vec<_>>();
+    let errors = (0..fields.len())
+        .map(|i| format_ident!("__E{}", i))
+        .collect::<Vec<_>>();
+    let variants = variants(fields)?;
+    let tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let locals = (0..fields.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect::<Vec<_>>();
+
+    let builder_doc = format!(
+        "An initializer for [`{name}`], which initializes each field in place, in declaration order"
+    );
+    let error_doc = format!("The error type of [`{builder}`]");
+
+    let builder_generics = if fields.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#inits,)*>)
+    };
+    let error_generics = if fields.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#errors,)*>)
+    };
+
+    let builder_def = match fields {
+        Fields::Named(_) => {
+            let defs = fields.iter().zip(&inits).map(|(field, init)| {
+                let vis = &field.vis;
+                let ident = &field.ident;
+                let doc = format!("the initializer for `{}`", ident.as_ref().unwrap());
+                quote!(#[doc = #doc] #vis #ident: #init)
+            });
+            quote!(#vis struct #builder #builder_generics { #(#defs,)* })
+        }
+        Fields::Unnamed(_) => {
+            let defs = fields.iter().zip(&inits).map(|(field, init)| {
+                let vis = &field.vis;
+                quote!(#vis #init)
+            });
+            quote!(#vis struct #builder #builder_generics ( #(#defs,)* );)
+        }
+        Fields::Unit => quote!(#vis struct #builder;),
+    };
+
+    let mut impl_generics = input.generics.clone();
+    let where_clause = impl_generics.make_where_clause();
+    for (init, ty) in inits.iter().zip(&tys) {
+        where_clause
+            .predicates
+            .push(parse_quote!(#init: ::ip_init::traits::TryInitialize<#ty>));
+    }
+    impl_generics.params.extend(
+        inits
+            .iter()
+            .map(|init| -> syn::GenericParam { parse_quote!(#init) }),
+    );
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
+    let variant_docs = members.iter().map(|member| match member {
+        Member::Named(ident) => format!("the initializer for `{ident}` failed"),
+        Member::Unnamed(index) => format!("the initializer for field {} failed", index.index),
+    });
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #[derive(Debug, Clone, Copy)]
+        #builder_def
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis enum #error #error_generics {
+            #(
+                #[doc = #variant_docs]
+                #variants(#errors),
+            )*
+        }
+
+        impl #impl_generics ::ip_init::traits::TryInitialize<#name #ty_generics> for #builder #builder_generics
+        #where_clause
+        {
+            type Error = #error<#(<#inits as ::ip_init::traits::TryInitialize<#tys>>::Error,)*>;
+
+            fn try_init<'__init>(
+                self,
+                mut uninit: ::ip_init::Uninit<'__init, #name #ty_generics>,
+            ) -> #core::result::Result<::ip_init::Init<'__init, #name #ty_generics>, Self::Error> {
+                let ptr = uninit.as_mut_ptr();
+
+                #(
+                    // SAFETY: the field is a place in the struct, so it's
+                    // * allocated for the field's layout
+                    // * writable for the field's layout
+                    // * readable for the field's layout after written to
+                    // and the field's `Init` is dropped or forgotten before `uninit` is used again
+                    let #locals = unsafe {
+                        ::ip_init::Uninit::from_raw(#core::ptr::addr_of_mut!((*ptr).#members))
+                    };
+                    // if this fails, the fields which were already initialized are dropped
+                    let #locals = #locals.try_init(self.#members).map_err(#error::#variants)?;
+                )*
+
+                // ownership of the fields is passed to the struct
+                #(#core::mem::forget(#locals);)*
+
+                // SAFETY: all fields were initialized
+                #core::result::Result::Ok(unsafe { uninit.assume_init() })
+            }
+        }
+    })
+}
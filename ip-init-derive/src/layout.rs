@@ -0,0 +1,128 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Type};
+
+/// the layout is only computed field by field for `#[repr(C)]` structs without
+/// `align` or `packed` modifiers
+fn check_repr_c(input: &DeriveInput) -> syn::Result<()> {
+    let mut repr_c = false;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+                Ok(())
+            } else {
+                Err(meta.error("`LayoutProvider` only supports `#[repr(C)]`"))
+            }
+        })?;
+    }
+
+    if repr_c {
+        Ok(())
+    } else {
+        Err(Error::new(
+            Span::call_site(),
+            "`LayoutProvider` can only be derived for `#[repr(C)]` structs",
+        ))
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`LayoutProvider` can only be derived for structs",
+            ))
+        }
+    };
+
+    check_repr_c(&input)?;
+
+    let (tail, header) = match fields.iter().collect::<Vec<_>>().split_last() {
+        Some((tail, header)) => (
+            *tail,
+            header.iter().map(|field| &field.ty).collect::<Vec<_>>(),
+        ),
+        None => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`LayoutProvider` can only be derived for structs whose last field is a slice",
+            ))
+        }
+    };
+
+    let elem = match &tail.ty {
+        Type::Slice(slice) => &slice.elem,
+        ty => {
+            return Err(Error::new_spanned(
+                ty,
+                "`LayoutProvider` can only be derived for structs whose last field is a slice",
+            ))
+        }
+    };
+
+    let core = quote!(::ip_init::macros::core);
+    let name = &input.ident;
+    let vis = &input.vis;
+    let provider = format_ident!("{}LayoutProvider", name);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut impl_generics = input.generics.clone();
+    impl_generics.params.push(parse_quote!(__I: ?Sized));
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
+    let provider_doc =
+        format!("A layout provider for [`{name}`], with the given length of it's trailing slice");
+
+    Ok(quote! {
+        #[doc = #provider_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #provider {
+            len: usize,
+        }
+
+        impl #provider {
+            /// Create a new layout provider for a trailing slice of length `len`
+            #vis const fn new(len: usize) -> Self {
+                Self { len }
+            }
+
+            /// The length of the trailing slice
+            #vis const fn data_len(&self) -> usize {
+                self.len
+            }
+        }
+
+        // SAFETY: the layout is computed with the `#[repr(C)]` algorithm, field by field,
+        // and the metadata of the struct is the length of it's trailing slice
+        unsafe impl #impl_generics ::ip_init::traits::LayoutProvider<#name #ty_generics, __I>
+            for #provider
+        #where_clause
+        {
+            #[inline]
+            fn layout_for(
+                &self,
+                _init: &__I,
+            ) -> #core::result::Result<#core::alloc::Layout, #core::alloc::LayoutError> {
+                let layout = #core::alloc::Layout::new::<()>();
+                #(
+                    let (layout, _) = layout.extend(#core::alloc::Layout::new::<#header>())?;
+                )*
+                let (layout, _) = layout.extend(#core::alloc::Layout::array::<#elem>(self.len)?)?;
+                #core::result::Result::Ok(layout.pad_to_align())
+            }
+
+            #[inline]
+            fn cast(&self, _init: &__I, ptr: *mut u8) -> *mut #name #ty_generics {
+                ::ip_init::macros::tail_slice_from_raw_parts(ptr, self.len)
+            }
+        }
+    })
+}
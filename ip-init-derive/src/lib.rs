@@ -0,0 +1,62 @@
+//! derive macros for `ip-init`, these are re-exported from `ip-init` with the `derive` feature
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod init;
+mod layout;
+mod project;
+mod zeroable;
+
+/// Derive typed projections from `Uninit<Self>` and `PinnedUninit<Self>` to the fields of a struct
+///
+/// See `ip_init::project::ProjectUninit` for details, and use `#[project_uninit(PinnedDrop)]`
+/// to drop the struct with `ip_init::project::PinnedDrop` instead of `Drop`, or
+/// `#[project_uninit(unsafe_pin_project)]` to leave the pinning checks to `#[pin_project]`
+#[proc_macro_derive(ProjectUninit, attributes(pin, project_uninit))]
+pub fn derive_project_uninit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    project::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive an initializer which initializes a struct field by field
+///
+/// For `struct Foo { a: A, b: B }` this generates `FooInit<IA, IB> { a: IA, b: IB }`, which
+/// implements `TryInitialize<Foo>` when each field's initializer implements `TryInitialize`
+/// for that field (plain values do). The fields are initialized in place in declaration order,
+/// and if one of them fails the fields which were already initialized are dropped. The error
+/// is a `FooInitError` with a variant for each field.
+#[proc_macro_derive(Init)]
+pub fn derive_init(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    init::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `ip_init::zeroed::Zeroable`, which requires all fields to be `Zeroable`
+///
+/// Enums must have a `C` or primitive repr, and only the fields of the variant
+/// with discriminant 0 need to be `Zeroable`
+#[proc_macro_derive(Zeroable)]
+pub fn derive_zeroable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    zeroable::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive a layout provider for a `#[repr(C)]` struct whose last field is a slice
+///
+/// For `struct Foo { a: A, data: [T] }` this generates `FooLayoutProvider`, which is created
+/// from the length of the trailing slice with `FooLayoutProvider::new(len)`, and implements
+/// `ip_init::traits::LayoutProvider<Foo>`
+#[proc_macro_derive(LayoutProvider)]
+pub fn derive_layout_provider(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    layout::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,295 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Fields, Generics, Member};
+
+fn is_pinned(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("pin"))
+}
+
+struct Options {
+    pinned_drop: bool,
+    /// `pin_project` already ensures that the struct doesn't implement `Drop` or `Unpin`,
+    /// so the derive's own checks would conflict with it
+    pin_project: bool,
+}
+
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut options = Options {
+        pinned_drop: false,
+        pin_project: false,
+    };
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("project_uninit") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("PinnedDrop") {
+                options.pinned_drop = true;
+                Ok(())
+            } else if meta.path.is_ident("unsafe_pin_project") {
+                options.pin_project = true;
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown `project_uninit` option, expected `PinnedDrop` or `unsafe_pin_project`",
+                ))
+            }
+        })?;
+    }
+
+    if options.pinned_drop && options.pin_project {
+        return Err(Error::new(
+            Span::call_site(),
+            "use `pin_project`'s `PinnedDrop` together with `#[pin_project]`",
+        ));
+    }
+
+    Ok(options)
+}
+
+fn check_not_packed(input: &DeriveInput) -> syn::Result<()> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                return Err(meta.error("`ProjectUninit` can't be derived for packed structs"));
+            }
+
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn with_lifetime(generics: &Generics, lifetime: syn::Lifetime) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!(#lifetime));
+    generics
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`ProjectUninit` can only be derived for structs",
+            ))
+        }
+    };
+
+    check_not_packed(&input)?;
+    let options = parse_options(&input)?;
+
+    let core = quote!(::ip_init::macros::core);
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let proj_generics = with_lifetime(&input.generics, parse_quote!('__proj));
+    let (_, proj_ty_generics, _) = proj_generics.split_for_impl();
+
+    let uninit_proj = format_ident!("__{}UninitProjection", name);
+    let pinned_proj = format_ident!("__{}PinnedUninitProjection", name);
+
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        })
+        .collect::<Vec<_>>();
+
+    let uninit_tys = fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote!(::ip_init::Uninit<'__proj, #ty>)
+    });
+    let pinned_tys = fields.iter().map(|field| {
+        let ty = &field.ty;
+        if is_pinned(field) {
+            quote!(::ip_init::PinnedUninit<'__proj, #ty>)
+        } else {
+            quote!(::ip_init::Uninit<'__proj, #ty>)
+        }
+    });
+
+    let uninit_values = members.iter().map(
+        |member| quote!(::ip_init::Uninit::from_raw(#core::ptr::addr_of_mut!((*ptr).#member))),
+    );
+    let pinned_values = fields.iter().zip(&members).map(|(field, member)| {
+        let uninit = quote!(::ip_init::Uninit::from_raw(#core::ptr::addr_of_mut!((*ptr).#member)));
+        if is_pinned(field) {
+            quote!(::ip_init::PinnedUninit::new_unchecked(#uninit))
+        } else {
+            uninit
+        }
+    });
+
+    let define = |proj: &syn::Ident, tys: Vec<TokenStream>| {
+        let body = match fields {
+            Fields::Named(_) => {
+                let defs = fields.iter().zip(tys).map(|(field, ty)| {
+                    let vis = &field.vis;
+                    let ident = &field.ident;
+                    quote!(#vis #ident: #ty)
+                });
+                quote!(#where_clause { #(#defs,)* })
+            }
+            Fields::Unnamed(_) => {
+                let defs = fields.iter().zip(tys).map(|(field, ty)| {
+                    let vis = &field.vis;
+                    quote!(#vis #ty)
+                });
+                quote!(( #(#defs,)* ) #where_clause;)
+            }
+            Fields::Unit => unreachable!(),
+        };
+
+        quote! {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #vis struct #proj #proj_generics #body
+        }
+    };
+
+    let construct = |proj: &syn::Ident, values: Vec<TokenStream>| match fields {
+        Fields::Named(_) => quote!(#proj { #(#members: #values,)* }),
+        Fields::Unnamed(_) => quote!(#proj ( #(#values,)* )),
+        Fields::Unit => unreachable!(),
+    };
+
+    let (definitions, uninit_ty, pinned_ty, uninit_body, pinned_body) = if fields.is_empty() {
+        let body = quote!(let _ = uninit;);
+        (quote!(), quote!(()), quote!(()), body.clone(), body)
+    } else {
+        let uninit_construct = construct(&uninit_proj, uninit_values.collect());
+        let pinned_construct = construct(&pinned_proj, pinned_values.collect());
+        let definitions = [
+            define(&uninit_proj, uninit_tys.collect()),
+            define(&pinned_proj, pinned_tys.collect()),
+        ];
+
+        (
+            quote!(#(#definitions)*),
+            quote!(#uninit_proj #proj_ty_generics),
+            quote!(#pinned_proj #proj_ty_generics),
+            quote! {
+                let ptr = uninit.as_mut_ptr();
+                // SAFETY: the fields are disjoint places in the struct, so they are
+                // * allocated for their field's layout
+                // * writable for their field's layout
+                // * readable for their field's layout after written to
+                // and they are borrowed from `uninit`, so they can't outlive it
+                unsafe { #uninit_construct }
+            },
+            quote! {
+                let ptr = uninit.as_mut_ptr();
+                // SAFETY: see `project_uninit`, and `#[pin]` fields are structurally pinned
+                // because the struct doesn't implement `Drop` and is only `Unpin` if all
+                // of it's `#[pin]` fields are `Unpin`
+                unsafe { #pinned_construct }
+            },
+        )
+    };
+
+    let unpin_generics = with_lifetime(&input.generics, parse_quote!('__pin));
+    let (unpin_impl_generics, unpin_ty_generics, _) = unpin_generics.split_for_impl();
+    let unpin_where = where_clause.map(|where_clause| &where_clause.predicates);
+    let unpin_fields = fields.iter().enumerate().map(|(i, field)| {
+        let ident = format_ident!("__field{}", i);
+        let ty = &field.ty;
+        if is_pinned(field) {
+            quote!(#ident: #ty)
+        } else {
+            quote!(#ident: #core::marker::PhantomData<fn() -> #ty>)
+        }
+    });
+
+    let drop_check = if options.pinned_drop {
+        quote! {
+            impl #impl_generics #core::ops::Drop for #name #ty_generics #where_clause {
+                fn drop(&mut self) {
+                    // SAFETY: the value is never used again after it's dropped,
+                    // so it's fine to treat it as pinned
+                    let this = unsafe { #core::pin::Pin::new_unchecked(self) };
+                    // SAFETY: this is only called from `Drop::drop`
+                    let token = unsafe { ::ip_init::project::OnlyCallFromDrop::new() };
+                    ::ip_init::project::PinnedDrop::drop(this, token)
+                }
+            }
+        }
+    } else {
+        quote! {
+            // moving out of a `#[pin]` field in `Drop` would break structural pinning
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: #core::ops::Drop> MustNotImplDrop for T {}
+            impl #impl_generics MustNotImplDrop for #name #ty_generics #where_clause {}
+        }
+    };
+
+    let pinning_checks = if options.pin_project {
+        quote!()
+    } else {
+        quote! {
+            const _: () = {
+                #drop_check
+
+                // the struct is only `Unpin` if all of it's `#[pin]` fields are,
+                // and this conflicts with any other `Unpin` impl
+                #[allow(dead_code)]
+                pub struct __Unpin #unpin_generics #where_clause {
+                    __pin: #core::marker::PhantomData<fn() -> &'__pin ()>,
+                    #(#unpin_fields,)*
+                }
+
+                impl #unpin_impl_generics #core::marker::Unpin for #name #ty_generics
+                where
+                    __Unpin #unpin_ty_generics: #core::marker::Unpin,
+                    #unpin_where
+                {
+                }
+            };
+        }
+    };
+
+    Ok(quote! {
+        #definitions
+
+        // SAFETY: every field is projected to it's own place in the struct, and only
+        // `#[pin]` fields are projected to `PinnedUninit`
+        unsafe impl #impl_generics ::ip_init::project::ProjectUninit for #name #ty_generics #where_clause {
+            type Projection<'__proj> = #uninit_ty where Self: '__proj;
+            type PinnedProjection<'__proj> = #pinned_ty where Self: '__proj;
+
+            #[inline]
+            fn project_uninit<'__proj>(
+                uninit: &'__proj mut ::ip_init::Uninit<'_, Self>,
+            ) -> Self::Projection<'__proj> {
+                #uninit_body
+            }
+
+            #[inline]
+            fn project_pinned_uninit<'__proj>(
+                uninit: &'__proj mut ::ip_init::PinnedUninit<'_, Self>,
+            ) -> Self::PinnedProjection<'__proj> {
+                #pinned_body
+            }
+        }
+
+        #pinning_checks
+    })
+}
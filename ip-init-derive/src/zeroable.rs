@@ -0,0 +1,128 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, punctuated::Punctuated, Data, DeriveInput, Error, Expr, ExprLit, ExprUnary, Lit,
+    Token, UnOp, Variant,
+};
+
+const TAG_REPRS: &[&str] = &[
+    "C", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// an enum's tag is only guaranteed to be it's discriminant with a `C` or primitive repr
+fn has_tag_repr(input: &DeriveInput) -> syn::Result<bool> {
+    let mut has_tag_repr = false;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if TAG_REPRS.iter().any(|repr| meta.path.is_ident(repr)) {
+                has_tag_repr = true;
+            }
+
+            // skip the arguments of `align(N)` and `packed(N)`
+            if meta.input.peek(syn::token::Paren) {
+                let args;
+                syn::parenthesized!(args in meta.input);
+                args.parse::<TokenStream>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(has_tag_repr)
+}
+
+fn discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant(expr).map(|value| -value),
+        Expr::Group(group) => discriminant(&group.expr),
+        Expr::Paren(paren) => discriminant(&paren.expr),
+        _ => None,
+    }
+}
+
+/// the variant which is represented by a zero tag
+fn zero_variant(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Token![,]>,
+) -> syn::Result<Variant> {
+    // the discriminant of the next variant if it isn't explicit, `None` when it's unknown
+    let mut next = Some(0);
+
+    for variant in variants {
+        let value = match &variant.discriminant {
+            Some((_, expr)) => match discriminant(expr) {
+                Some(value) => Some(value),
+                None => return Err(Error::new_spanned(
+                    expr,
+                    "`Zeroable` can only be derived for enums with integer literal discriminants",
+                )),
+            },
+            None => next,
+        };
+
+        if value == Some(0) {
+            return Ok(variant.clone());
+        }
+
+        next = value.and_then(|value| value.checked_add(1));
+    }
+
+    Err(Error::new_spanned(
+        &input.ident,
+        "`Zeroable` can only be derived for enums with a variant whose discriminant is 0",
+    ))
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => data.fields.clone(),
+        Data::Enum(data) => {
+            if !has_tag_repr(&input)? {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`Zeroable` can only be derived for enums with a `C` or primitive repr",
+                ));
+            }
+
+            zero_variant(&input, &data.variants)?.fields
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`Zeroable` can't be derived for unions",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in &fields {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::ip_init::zeroed::Zeroable));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        // SAFETY: all of the fields are `Zeroable`, and for enums the variant with
+        // discriminant 0 is represented by a zero tag, because of it's repr
+        unsafe impl #impl_generics ::ip_init::zeroed::Zeroable for #name #ty_generics
+        #where_clause
+        {}
+    })
+}
@@ -0,0 +1,54 @@
+#![feature(test)]
+
+extern crate test;
+
+use core::mem::MaybeUninit;
+
+use ip_init::{slice::SliceWriter, Uninit};
+use test::{black_box, Bencher};
+
+const LEN: usize = 4096;
+
+#[bench]
+fn bench_write(b: &mut Bencher) {
+    let mut array = [MaybeUninit::<u64>::uninit(); LEN];
+
+    b.iter(|| {
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+        let mut writer = SliceWriter::new(uninit);
+        let mut value = 0;
+        while !writer.is_finished() {
+            writer.write(black_box(value));
+            value += 1;
+        }
+        black_box(writer.finish());
+    })
+}
+
+#[bench]
+fn bench_for_each(b: &mut Bencher) {
+    let mut array = [MaybeUninit::<u64>::uninit(); LEN];
+
+    b.iter(|| {
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+        let mut value = 0;
+        let init = SliceWriter::new(uninit).for_each(|uninit| {
+            value += 1;
+            uninit.write(black_box(value))
+        });
+        black_box(init);
+    })
+}
+
+#[bench]
+fn bench_copy_from_slice(b: &mut Bencher) {
+    let mut array = [MaybeUninit::<u64>::uninit(); LEN];
+    let source = [7; LEN];
+
+    b.iter(|| {
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+        let mut writer = SliceWriter::new(uninit);
+        writer.copy_from_slice(black_box(&source));
+        black_box(writer.finish());
+    })
+}
@@ -0,0 +1,242 @@
+//! create and initialize atomically reference counted allocations in place
+//!
+//! The value is constructed directly in the `Arc` allocation, after the reference counts,
+//! instead of being moved into it.
+
+use core::{fmt::Debug, mem::MaybeUninit, pin::Pin};
+
+use ::alloc::sync::{Arc, UniqueArc, Weak};
+
+use crate::{
+    traits::{TryInitialize, TryPinInitialize},
+    Init, Uninit,
+};
+
+/// create a new T, and initialize it in place
+pub fn emplace<T, I>(init: I) -> Arc<T>
+where
+    I: TryInitialize<T>,
+    I::Error: Debug,
+{
+    match try_emplace(init) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new T, and pin initialize it in place
+pub fn emplace_pin<T, I>(init: I) -> Pin<Arc<T>>
+where
+    I: TryPinInitialize<T>,
+    I::Error: Debug,
+{
+    match try_emplace_pin(init) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new T, and attempt to initialize it in place
+///
+/// If initialization fails, the allocation is freed and the error is returned
+pub fn try_emplace<T, I>(init: I) -> Result<Arc<T>, I::Error>
+where
+    I: TryInitialize<T>,
+{
+    let mut arc = Arc::<T>::new_uninit();
+    let slot = unique_slot(&mut arc);
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    unsafe { crate::raw::try_init_in_place(init, slot.as_mut_ptr())? }
+
+    // SAFETY: the value was just initialized
+    Ok(unsafe { arc.assume_init() })
+}
+
+/// create a new T, and attempt to pin initialize it in place
+///
+/// If initialization fails, the allocation is freed and the error is returned
+pub fn try_emplace_pin<T, I>(init: I) -> Result<Pin<Arc<T>>, I::Error>
+where
+    I: TryPinInitialize<T>,
+{
+    let mut arc = Arc::<T>::new_uninit();
+    let slot = unique_slot(&mut arc);
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    // and the value is never moved out of the allocation
+    unsafe { crate::raw::try_pin_init_in_place(init, slot.as_mut_ptr())? }
+
+    // SAFETY: the value was just initialized, and `Arc` never gives out
+    // mutable access to a shared value, so it can't be moved
+    Ok(unsafe { Pin::new_unchecked(arc.assume_init()) })
+}
+
+/// create a new T with access to a [`Weak`] pointer to itself, and initialize it in place
+///
+/// This is the in-place analogue of [`Arc::new_cyclic`]
+pub fn emplace_cyclic<T, F, E>(f: F) -> Arc<T>
+where
+    F: for<'a> FnOnce(&Weak<T>, Uninit<'a, T>) -> Result<Init<'a, T>, E>,
+    E: Debug,
+{
+    match try_emplace_cyclic(f) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new T with access to a [`Weak`] pointer to itself, and attempt to initialize it in place
+///
+/// The [`Weak`] can't be upgraded until the initialization completes, but it may be cloned
+/// and stored inside the value to build self-referential graphs. If initialization fails,
+/// then the value is never created and all of the [`Weak`] pointers will fail to upgrade.
+pub fn try_emplace_cyclic<T, F, E>(f: F) -> Result<Arc<T>, E>
+where
+    F: for<'a> FnOnce(&Weak<T>, Uninit<'a, T>) -> Result<Init<'a, T>, E>,
+{
+    let mut unique = UniqueArc::new(MaybeUninit::<T>::uninit());
+    // the strong count stays at zero until `into_arc`, so this weak pointer can't be upgraded
+    // to observe the uninitialized value
+    let weak = UniqueArc::downgrade(&unique);
+    // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and the weak pointer owns
+    // its weak reference
+    let weak = unsafe { Weak::from_raw(weak.into_raw().cast::<T>()) };
+
+    let init = f(&weak, Uninit::from_maybe_uninit(&mut unique))?;
+    // ownership of the value is passed to the `Arc`
+    core::mem::forget(init);
+
+    // SAFETY: the value was just initialized
+    Ok(unsafe { UniqueArc::into_arc(unique).assume_init() })
+}
+
+/// create a new slice of length `len`, and initialize it in place
+pub fn emplace_slice<T, I>(len: usize, init: I) -> Arc<[T]>
+where
+    I: TryInitialize<[T]>,
+    I::Error: Debug,
+{
+    match try_emplace_slice(len, init) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new slice of length `len`, and attempt to initialize it in place
+///
+/// The elements are initialized in the final allocation. If initialization fails,
+/// the elements which were already initialized are dropped by the initializer,
+/// the allocation is freed, and the error is returned
+pub fn try_emplace_slice<T, I>(len: usize, init: I) -> Result<Arc<[T]>, I::Error>
+where
+    I: TryInitialize<[T]>,
+{
+    let mut arc = Arc::<[T]>::new_uninit_slice(len);
+    let slot = unique_slot(&mut arc);
+
+    let init = crate::Uninit::from_maybe_uninit_slice(slot).try_init(init)?;
+    // ownership of the elements is passed to the `Arc`
+    core::mem::forget(init);
+
+    // SAFETY: all elements were just initialized
+    Ok(unsafe { arc.assume_init() })
+}
+
+/// create a new string which copies `value`, without an intermediate `String`
+pub fn emplace_str(value: &str) -> Arc<str> {
+    match try_emplace_str(value.len(), crate::str::StrInit::new(value)) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new string of `len` bytes from the given chars
+///
+/// # Panics
+///
+/// If the chars don't encode to exactly `len` bytes
+pub fn emplace_str_chars<C: IntoIterator<Item = char>>(len: usize, chars: C) -> Arc<str> {
+    match try_emplace_str(len, crate::str::CharsInit::new(chars)) {
+        Ok(arc) => arc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new string of `len` bytes, and attempt to initialize it in place
+pub fn try_emplace_str<I>(len: usize, init: I) -> Result<Arc<str>, I::Error>
+where
+    I: TryInitialize<str>,
+{
+    let mut arc = Arc::<[u8]>::new_uninit_slice(len);
+    let slot = unique_slot(&mut arc) as *mut [core::mem::MaybeUninit<u8>] as *mut str;
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    unsafe { crate::raw::try_init_in_place(init, slot)? }
+
+    // SAFETY: the bytes were just initialized with a valid string
+    // and `str` has the same layout as `[u8]`
+    Ok(unsafe { Arc::from_raw(Arc::into_raw(arc.assume_init()) as *const str) })
+}
+
+fn unique_slot<T: ?Sized>(arc: &mut Arc<T>) -> &mut T {
+    match Arc::get_mut(arc) {
+        Some(slot) => slot,
+        None => unreachable!("A new `Arc` is always unique"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_emplace() {
+        let arc = emplace::<[u32; 4], _>(crate::array::ArrayFromFn::new(|i| i as u32));
+        assert_eq!(*arc, [0, 1, 2, 3]);
+
+        let arc = emplace_pin::<[u32; 2], _>([1; 2]);
+        assert_eq!(*arc, [1; 2]);
+
+        let err = try_emplace::<[u32; 4], _>(crate::slice::SliceIterInit::new(0..2));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_cyclic() {
+        struct Node {
+            this: Weak<Node>,
+            value: u32,
+        }
+
+        let node = emplace_cyclic::<Node, _, core::convert::Infallible>(|weak, uninit| {
+            assert!(weak.upgrade().is_none());
+            Ok(uninit.write(Node {
+                this: weak.clone(),
+                value: 10,
+            }))
+        });
+        let this = node.this.upgrade().unwrap();
+        assert!(Arc::ptr_eq(&node, &this));
+        assert_eq!(this.value, 10);
+
+        let err = try_emplace_cyclic::<Node, _, ()>(|_, _| Err(()));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_slice() {
+        let arc = emplace_slice(3, crate::slice::SliceIterInit::new(0..));
+        assert_eq!(*arc, [0, 1, 2]);
+
+        let err = try_emplace_slice::<u32, _>(4, crate::slice::SliceIterInit::new(0..2));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_str() {
+        assert_eq!(&*emplace_str("hello"), "hello");
+        assert_eq!(&*emplace_str_chars(3, "abc".chars()), "abc");
+        assert!(try_emplace_str(2, crate::str::CharsInit::new("abc".chars())).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+//! create and initialize values in place in an arena
+//!
+//! Any arena which implements [`Allocator`] for a shared reference can be used, for example
+//! `&bumpalo::Bump` with bumpalo's `allocator_api` feature. The values live as long as the borrow of the arena.
+//!
+//! The values are never deallocated on their own, so unlike a `Box` the layout provider may
+//! pad the allocation, for example with a [`PaddedLayoutProvider`](crate::layout::PaddedLayoutProvider)
+//! to keep values which are used by different threads on separate cache lines.
+
+#[cfg(not(no_global_oom_handling))]
+use core::fmt::Debug;
+use core::{alloc::Allocator, pin::Pin};
+
+use ::alloc::boxed::Box;
+
+use crate::{
+    boxed::AllocError,
+    traits::{LayoutProvider, TryInitialize, TryPinInitialize},
+};
+
+/// create a new T in the arena, and initialize it in place
+///
+/// The value is never dropped, just like with [`Box::leak`]
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_in<'a, T: ?Sized, A: ?Sized, L, I>(arena: &'a A, provider: L, init: I) -> &'a mut T
+where
+    &'a A: Allocator,
+    I: TryInitialize<T>,
+    L: LayoutProvider<T, I>,
+    I::Error: Debug,
+{
+    match try_emplace_in(arena, provider, init) {
+        Ok(value) => value,
+        Err(ref err) => crate::boxed::handle(err),
+    }
+}
+
+/// create a new T in the arena, and attempt to initialize it in place
+///
+/// The value is never dropped, just like with [`Box::leak`]
+// each call returns a new allocation, so the returned references never alias
+#[allow(clippy::mut_from_ref)]
+pub fn try_emplace_in<'a, T: ?Sized, A: ?Sized, L, I>(
+    arena: &'a A,
+    provider: L,
+    init: I,
+) -> Result<&'a mut T, AllocError<I::Error>>
+where
+    &'a A: Allocator,
+    I: TryInitialize<T>,
+    L: LayoutProvider<T, I>,
+{
+    // SAFETY: `try_init_in_place` initializes the pointer if it returns `Ok`
+    // and the pointer is allocated for T (`LayoutProvider`), and is valid for
+    // writes and reads (after writes)
+    let ptr = unsafe {
+        crate::boxed::try_emplace_raw_in(&arena, provider, init, false, |init, ptr| {
+            crate::raw::try_init_in_place(init, ptr)
+        })?
+    };
+
+    // SAFETY: the value is initialized, and the allocation is never deallocated,
+    // so it stays valid for as long as the arena is borrowed
+    Ok(unsafe { &mut *ptr.as_ptr() })
+}
+
+/// create a new T in the arena, attempt to pin initialize it in place, and pass it to `f`
+///
+/// A pinned value must be dropped before it's memory is reused, but an arena may free
+/// it's memory without running any destructors. So the pinned value is only accessible
+/// inside `f`, and is dropped afterwards (even if `f` panics).
+pub fn with_pinned_in<'a, T: ?Sized, A: ?Sized, L, I, R>(
+    arena: &'a A,
+    provider: L,
+    init: I,
+    f: impl FnOnce(Pin<&mut T>) -> R,
+) -> Result<R, AllocError<I::Error>>
+where
+    &'a A: Allocator,
+    I: TryPinInitialize<T>,
+    L: LayoutProvider<T, I>,
+{
+    // SAFETY: `try_pin_init_in_place` initializes the pointer if it returns `Ok`
+    // and the pointer is allocated for T (`LayoutProvider`), and is valid for
+    // writes and reads (after writes)
+    let ptr = unsafe {
+        // the guard deallocates with the layout of the value, so the layout must be exact
+        crate::boxed::try_emplace_raw_in(&arena, provider, init, true, |init, ptr| {
+            crate::raw::try_pin_init_in_place(init, ptr)
+        })?
+    };
+
+    // SAFETY: the pointer is now initialized and allocated via `arena`
+    // the box acts as a drop guard, which can't be leaked because it's local
+    let mut guard = unsafe { Box::from_raw_in(ptr.as_ptr(), arena) };
+
+    // SAFETY: the value was pin initialized, and will be dropped in place by the guard
+    Ok(f(unsafe { Pin::new_unchecked(&mut *guard) }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::layout::{PaddedLayoutProvider, SizedLayoutProvider, SliceLayoutProvider};
+    use ::alloc::alloc::Global;
+    use core::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+    /// an arena which is only an allocator through a shared reference, like `bumpalo::Bump`
+    #[derive(Default)]
+    struct Mock {
+        live: Cell<usize>,
+        size: Cell<usize>,
+    }
+
+    // SAFETY: all allocations are forwarded to `Global`
+    unsafe impl Allocator for &Mock {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+            self.live.set(self.live.get() + 1);
+            self.size.set(layout.size());
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - 1);
+            // SAFETY: the pointer was allocated by `Global` in `allocate`
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn test_emplace_in() {
+        let arena = Global;
+        let slice: &mut [u32] = emplace_in(
+            &arena,
+            SliceLayoutProvider(3),
+            crate::slice::RepeatInit::new(7),
+        );
+        assert_eq!(*slice, [7, 7, 7]);
+
+        let err = try_emplace_in::<[u32], _, _, _>(
+            &arena,
+            SliceLayoutProvider(3),
+            crate::slice::SliceIterInit::new(0..1),
+        );
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+
+    #[test]
+    fn test_with_pinned_in() {
+        let arena = Global;
+        let value = with_pinned_in::<u8, _, _, _, _>(&arena, SizedLayoutProvider, 5, |value| {
+            let value = value.get_mut();
+            *value += 1;
+            *value
+        });
+        assert_eq!(value.ok(), Some(6));
+    }
+
+    #[test]
+    fn test_shared_allocator() {
+        let arena = Mock::default();
+        let value: &mut u32 = emplace_in(&arena, SizedLayoutProvider, 3);
+        *value += 1;
+        assert_eq!(*value, 4);
+        assert_eq!(arena.live.get(), 1);
+
+        let slice = try_emplace_in::<[u8], _, _, _>(
+            &arena,
+            SliceLayoutProvider(2),
+            crate::slice::RepeatInit::new(1),
+        );
+        assert_eq!(slice.ok().map(|slice| &*slice), Some(&[1, 1][..]));
+        assert_eq!(arena.live.get(), 2);
+
+        let value =
+            with_pinned_in::<u8, _, _, _, _>(&arena, SizedLayoutProvider, 5, |value| *value);
+        assert_eq!(value.ok(), Some(5));
+        assert_eq!(arena.live.get(), 2);
+    }
+
+    #[test]
+    fn test_padded() {
+        let arena = Mock::default();
+        let value: &mut u8 = emplace_in(
+            &arena,
+            PaddedLayoutProvider::new(SizedLayoutProvider, 64),
+            1,
+        );
+        assert_eq!(*value, 1);
+        assert_eq!(arena.size.get(), 64);
+    }
+}
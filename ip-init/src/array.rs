@@ -3,7 +3,17 @@
 //! this allows you to safely initialize the entire uninitialized slice efficiently,
 //! and drop initialized elements on error.
 
-use crate::traits::{TryInitialize, TryPinInitialize};
+mod writer;
+
+pub use writer::ArrayWriter;
+
+use core::mem::MaybeUninit;
+
+use crate::{
+    slice::{PinSliceWriter, SliceWriter},
+    traits::{Initialize, TryInitialize, TryPinInitialize},
+    Uninit,
+};
 
 /// An array initializer
 pub struct ArrayInit<I>(I);
@@ -43,3 +53,321 @@ impl<I: TryPinInitialize<[T]>, T, const N: usize> TryPinInitialize<[T; N]> for A
         }
     }
 }
+
+/// An array initializer which initializes each element by calling the function with it's index
+///
+/// This is the in-place equivalent of [`core::array::from_fn`], and can also initialize slices
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayFromFn<F>(F);
+
+impl<F> ArrayFromFn<F> {
+    /// Create a new array initializer
+    pub fn new<T>(func: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self(func)
+    }
+}
+
+impl<F: FnMut(usize) -> T, T> TryInitialize<[T]> for ArrayFromFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_init(mut self, ptr: Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut index = 0;
+        Ok(SliceWriter::new(ptr).for_each(|uninit| {
+            let init = uninit.write((self.0)(index));
+            index += 1;
+            init
+        }))
+    }
+}
+
+impl<F: FnMut(usize) -> T, T> TryPinInitialize<[T]> for ArrayFromFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_pin_init(
+        mut self,
+        ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        let mut index = 0;
+        Ok(PinSliceWriter::new(ptr).for_each(|uninit| {
+            let init = uninit.write((self.0)(index));
+            index += 1;
+            init
+        }))
+    }
+}
+
+impl<F: FnMut(usize) -> T, T, const N: usize> TryInitialize<[T; N]> for ArrayFromFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_init(self, ptr: Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<F: FnMut(usize) -> T, T, const N: usize> TryPinInitialize<[T; N]> for ArrayFromFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+/// An array initializer which tries to initialize each element by calling the function with it's index
+///
+/// This is the fallible version of [`ArrayFromFn`]
+#[derive(Debug, Clone, Copy)]
+pub struct TryArrayFromFn<F>(F);
+
+impl<F> TryArrayFromFn<F> {
+    /// Create a new array initializer
+    pub fn new<T, E>(func: F) -> Self
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        Self(func)
+    }
+}
+
+impl<F: FnMut(usize) -> Result<T, E>, T, E> TryInitialize<[T]> for TryArrayFromFn<F> {
+    type Error = E;
+
+    fn try_init(mut self, ptr: Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut index = 0;
+        SliceWriter::new(ptr).try_for_each(|uninit| {
+            let init = uninit.write((self.0)(index)?);
+            index += 1;
+            Ok(init)
+        })
+    }
+}
+
+impl<F: FnMut(usize) -> Result<T, E>, T, E> TryPinInitialize<[T]> for TryArrayFromFn<F> {
+    type Error = E;
+
+    fn try_pin_init(
+        mut self,
+        ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        let mut index = 0;
+        PinSliceWriter::new(ptr).try_for_each(|uninit| {
+            let init = uninit.write((self.0)(index)?);
+            index += 1;
+            Ok(init)
+        })
+    }
+}
+
+impl<F: FnMut(usize) -> Result<T, E>, T, E, const N: usize> TryInitialize<[T; N]>
+    for TryArrayFromFn<F>
+{
+    type Error = E;
+
+    fn try_init(self, ptr: Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<F: FnMut(usize) -> Result<T, E>, T, E, const N: usize> TryPinInitialize<[T; N]>
+    for TryArrayFromFn<F>
+{
+    type Error = E;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+/// An array initializer which initializes each element of a `[T; N]` with it's own initializer
+///
+/// NOTE: `[I; N]` can't implement `TryInitialize<[T; N]>` directly, because that would overlap
+/// with `[T; N]` initializing itself
+#[derive(Debug, Clone, Copy)]
+pub struct PerElementInit<I, const N: usize>([I; N]);
+
+impl<I, const N: usize> PerElementInit<I, N> {
+    /// Create a new array initializer
+    pub fn new(inits: [I; N]) -> Self {
+        Self(inits)
+    }
+}
+
+impl<I: TryInitialize<T>, T, const N: usize> TryInitialize<[T; N]> for PerElementInit<I, N> {
+    type Error = I::Error;
+
+    fn try_init(self, ptr: Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        let mut writer = SliceWriter::new(ptr.into_slice());
+        for init in self.0 {
+            writer.try_write(init)?;
+        }
+        let init = writer.finish();
+        // SAFETY: this init has the same pointer as `ptr`, so it has the correct length
+        Ok(unsafe { init.try_into().unwrap_unchecked() })
+    }
+}
+
+impl<I: TryPinInitialize<T>, T, const N: usize> TryPinInitialize<[T; N]> for PerElementInit<I, N> {
+    type Error = I::Error;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        let mut writer = PinSliceWriter::new(ptr.into_slice());
+        for init in self.0 {
+            writer.try_write(init)?;
+        }
+        let init = writer.finish();
+        // SAFETY: this init has the same pointer as `ptr`, so it has the correct length
+        Ok(unsafe { init.try_into().unwrap_unchecked() })
+    }
+}
+
+/// A matrix initializer which initializes each element by calling the function with it's `(row, column)`
+///
+/// This can initialize `[[T; N]; M]` and `[[T; N]]`, and a row-major `[T]` can be initialized
+/// with [`Uninit::into_rows`]
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixFromFn<F>(F);
+
+impl<F> MatrixFromFn<F> {
+    /// Create a new matrix initializer
+    pub fn new<T>(func: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self(func)
+    }
+}
+
+impl<F: FnMut(usize, usize) -> T, T, const N: usize> TryInitialize<[[T; N]]> for MatrixFromFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_init(mut self, ptr: Uninit<[[T; N]]>) -> Result<crate::Init<[[T; N]]>, Self::Error> {
+        let mut row = 0;
+        Ok(SliceWriter::new(ptr).for_each(|uninit| {
+            let init = uninit.init(ArrayFromFn::new(|col| (self.0)(row, col)));
+            row += 1;
+            init
+        }))
+    }
+}
+
+impl<F: FnMut(usize, usize) -> T, T, const N: usize> TryPinInitialize<[[T; N]]>
+    for MatrixFromFn<F>
+{
+    type Error = core::convert::Infallible;
+
+    fn try_pin_init(
+        mut self,
+        ptr: crate::PinnedUninit<[[T; N]]>,
+    ) -> Result<crate::PinnedInit<[[T; N]]>, Self::Error> {
+        let mut row = 0;
+        Ok(PinSliceWriter::new(ptr).for_each(|uninit| {
+            let init = uninit.init(ArrayFromFn::new(|col| (self.0)(row, col)));
+            row += 1;
+            init
+        }))
+    }
+}
+
+impl<F: FnMut(usize, usize) -> T, T, const N: usize, const M: usize> TryInitialize<[[T; N]; M]>
+    for MatrixFromFn<F>
+{
+    type Error = core::convert::Infallible;
+
+    fn try_init(self, ptr: Uninit<[[T; N]; M]>) -> Result<crate::Init<[[T; N]; M]>, Self::Error> {
+        ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<F: FnMut(usize, usize) -> T, T, const N: usize, const M: usize> TryPinInitialize<[[T; N]; M]>
+    for MatrixFromFn<F>
+{
+    type Error = core::convert::Infallible;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[[T; N]; M]>,
+    ) -> Result<crate::PinnedInit<[[T; N]; M]>, Self::Error> {
+        ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+/// Initialize an array on the stack, and return it by value
+pub fn init_array<T, const N: usize>(init: impl Initialize<[T; N]>) -> [T; N] {
+    let mut slot = MaybeUninit::<[T; N]>::uninit();
+    let init = Uninit::from_maybe_uninit(&mut slot).init(init);
+    // the array is now owned by `slot`
+    init.into_raw();
+    // SAFETY: the slot was initialized by `init`
+    unsafe { slot.assume_init() }
+}
+
+/// Try to initialize an array on the stack, and return it by value
+///
+/// If initialization fails, the error is returned, and any partially initialized
+/// elements are dropped by the initializer
+pub fn try_init_array<T, I: TryInitialize<[T; N]>, const N: usize>(
+    init: I,
+) -> Result<[T; N], I::Error> {
+    let mut slot = MaybeUninit::<[T; N]>::uninit();
+    let init = Uninit::from_maybe_uninit(&mut slot).try_init(init)?;
+    // the array is now owned by `slot`
+    init.into_raw();
+    // SAFETY: the slot was initialized by `init`
+    Ok(unsafe { slot.assume_init() })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_init_array() {
+        let array: [u32; 4] = init_array(crate::slice::RepeatInit::new(3));
+        assert_eq!(array, [3; 4]);
+
+        let array: Result<[u32; 4], _> = try_init_array(crate::slice::SliceIterInit::new(0..2));
+        assert!(array.is_err());
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let array: [usize; 4] = init_array(ArrayFromFn::new(|i| i * 2));
+        assert_eq!(array, [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_per_element() {
+        let array: [u32; 3] = init_array(PerElementInit::new([1, 2, 3]));
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_matrix() {
+        let matrix: [[usize; 3]; 2] = init_array(MatrixFromFn::new(|row, col| row * 10 + col));
+        assert_eq!(matrix, [[0, 1, 2], [10, 11, 12]]);
+
+        let mut slot = [MaybeUninit::<usize>::uninit(); 6];
+        let rows = Uninit::from_maybe_uninit_slice(&mut slot)
+            .into_rows::<2>()
+            .ok()
+            .unwrap();
+        let init = rows.init(MatrixFromFn::new(|row, col| row * 10 + col));
+        assert_eq!(*init.into_flattened(), [0, 1, 10, 11, 20, 21]);
+
+        let mut slot = [MaybeUninit::<usize>::uninit(); 5];
+        assert!(Uninit::from_maybe_uninit_slice(&mut slot)
+            .into_rows::<2>()
+            .is_err());
+    }
+}
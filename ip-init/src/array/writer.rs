@@ -0,0 +1,195 @@
+use core::{marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    traits::{Initialize, TryInitialize},
+    Init, Uninit,
+};
+
+/// A writer to an uninitialized array, which tracks the number of initialized elements in it's type
+///
+/// [`ArrayWriter::finish`] only exists once all `N` elements have been written, so forgetting to
+/// initialize an element is a compile time error instead of a panic. And writing too many elements
+/// also fails to compile.
+///
+/// `write` is available for `WRITTEN < 32`, so this writer is meant for small arrays, and
+/// writing a 33rd element fails to compile with an error which names this limit.
+/// For larger arrays use [`SliceWriter`](crate::slice::SliceWriter)
+///
+/// ```compile_fail
+/// use ip_init::{array::ArrayWriter, Uninit};
+///
+/// let mut array = core::mem::MaybeUninit::<[u32; 2]>::uninit();
+/// let uninit = Uninit::from_maybe_uninit(&mut array);
+///
+/// // only one of the two elements was initialized
+/// let init = ArrayWriter::new(uninit).write(1).finish();
+/// ```
+pub struct ArrayWriter<'a, T, const N: usize, const WRITTEN: usize> {
+    ptr: NonNull<[T; N]>,
+    _lt: PhantomData<Uninit<'a, [T; N]>>,
+}
+
+// SAFETY: this only drops the T, so is trivially correct for `#[may_dangle]`
+unsafe impl<#[may_dangle] T, const N: usize, const WRITTEN: usize> Drop
+    for ArrayWriter<'_, T, N, WRITTEN>
+{
+    fn drop(&mut self) {
+        let ptr = core::ptr::slice_from_raw_parts_mut(self.ptr.as_ptr().cast::<T>(), WRITTEN);
+        // SAFETY: this only drops the initialized portion of the writer
+        unsafe { ptr.drop_in_place() }
+    }
+}
+
+impl<'a, T, const N: usize> ArrayWriter<'a, T, N, 0> {
+    /// create a new writer
+    pub fn new(uninit: Uninit<'a, [T; N]>) -> Self {
+        Self {
+            ptr: uninit.as_non_null_ptr(),
+            _lt: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ArrayWriter<'a, T, N, N> {
+    /// finish the writer and get an initialized array
+    pub fn finish(self) -> Init<'a, [T; N]> {
+        let ptr = self.ptr;
+        core::mem::forget(self);
+        // SAFETY: all `N` elements were initialized
+        unsafe { Init::from_raw_nonnull(ptr) }
+    }
+}
+
+impl<'a, T, const N: usize, const WRITTEN: usize> ArrayWriter<'a, T, N, WRITTEN> {
+    /// The number of elements which were initialized
+    #[inline(always)]
+    pub const fn initialized(&self) -> usize {
+        WRITTEN
+    }
+
+    /// The number of elements which are still uninitialized
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        N - WRITTEN
+    }
+
+    /// The initialized prefix of the array
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `WRITTEN` elements of the array are initialized
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr().cast::<T>(), WRITTEN) }
+    }
+
+    /// The initialized prefix of the array
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `WRITTEN` elements of the array are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr().cast::<T>(), WRITTEN) }
+    }
+
+    /// # Safety
+    ///
+    /// the first `NEXT` elements must be initialized
+    unsafe fn advance<const NEXT: usize>(self) -> ArrayWriter<'a, T, N, NEXT> {
+        let ptr = self.ptr;
+        core::mem::forget(self);
+        ArrayWriter {
+            ptr,
+            _lt: PhantomData,
+        }
+    }
+
+    /// `Written<WRITTEN>: Next<NEXT>` guarantees that `NEXT == WRITTEN + 1`
+    fn try_write_next<I: TryInitialize<T>, const NEXT: usize>(
+        self,
+        init: I,
+    ) -> Result<ArrayWriter<'a, T, N, NEXT>, I::Error>
+    where
+        Written<WRITTEN>: Next<NEXT>,
+    {
+        const {
+            assert!(
+                WRITTEN < N,
+                "tried to write past the end of the array in `ArrayWriter`"
+            );
+        }
+
+        // SAFETY: WRITTEN < N, so the slot is in bounds
+        let slot = unsafe { self.ptr.as_ptr().cast::<T>().add(WRITTEN) };
+        // SAFETY: the slot is in bounds of an `Uninit`, and is uninitialized
+        // if this fails, then self is dropped and drops the initialized prefix
+        unsafe { crate::raw::try_init_in_place(init, slot)? };
+        // SAFETY: the first `WRITTEN + 1` elements are now initialized
+        Ok(unsafe { self.advance() })
+    }
+}
+
+/// `WRITTEN` elements of an array were written
+pub struct Written<const WRITTEN: usize>;
+
+/// After one more write, `NEXT` elements of the array were written
+///
+/// This is only implemented for `Written<WRITTEN>: Next<{ WRITTEN + 1 }>` where `WRITTEN < 32`,
+/// and it can't be implemented outside of this module
+#[diagnostic::on_unimplemented(
+    message = "`ArrayWriter` can only write the first 32 elements of an array",
+    label = "the writer already wrote all 32 elements it supports",
+    note = "use `SliceWriter` to initialize larger arrays"
+)]
+pub trait Next<const NEXT: usize> {}
+
+impl<'a, T, const N: usize, const WRITTEN: usize> ArrayWriter<'a, T, N, WRITTEN> {
+    /// Try to initialize the next slot
+    ///
+    /// If this fails, the initialized prefix is dropped
+    pub fn try_write<I: TryInitialize<T>, const NEXT: usize>(
+        self,
+        init: I,
+    ) -> Result<ArrayWriter<'a, T, N, NEXT>, I::Error>
+    where
+        Written<WRITTEN>: Next<NEXT>,
+    {
+        self.try_write_next(init)
+    }
+
+    /// Initialize the next slot
+    pub fn write<I: Initialize<T>, const NEXT: usize>(self, init: I) -> ArrayWriter<'a, T, N, NEXT>
+    where
+        Written<WRITTEN>: Next<NEXT>,
+    {
+        match self.try_write_next(init) {
+            Ok(writer) => writer,
+            Err(inf) => match inf {},
+        }
+    }
+}
+
+macro_rules! impl_next {
+    ($($written:literal => $next:literal)*) => {$(
+        impl Next<$next> for Written<$written> {}
+    )*};
+}
+
+impl_next! {
+    0 => 1 1 => 2 2 => 3 3 => 4 4 => 5 5 => 6 6 => 7 7 => 8
+    8 => 9 9 => 10 10 => 11 11 => 12 12 => 13 13 => 14 14 => 15 15 => 16
+    16 => 17 17 => 18 18 => 19 19 => 20 20 => 21 21 => 22 22 => 23 23 => 24
+    24 => 25 25 => 26 26 => 27 27 => 28 28 => 29 29 => 30 30 => 31 31 => 32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_array_writer() {
+        let mut array = core::mem::MaybeUninit::<[u32; 3]>::uninit();
+        let uninit = Uninit::from_maybe_uninit(&mut array);
+
+        let writer = ArrayWriter::new(uninit).write(1).write(2);
+        assert_eq!(writer.as_slice(), [1, 2]);
+        let init = writer.write(3).finish();
+
+        assert_eq!(*init, [1, 2, 3]);
+    }
+}
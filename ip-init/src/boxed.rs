@@ -1,17 +1,27 @@
 //! create and initialize heap allocations in place
 
+#[cfg(not(no_global_oom_handling))]
+use core::fmt::Debug;
 use core::{
     alloc::{Layout, LayoutError},
-    fmt::Debug,
     pin::Pin,
     ptr::NonNull,
 };
 
 use ::alloc::{alloc, boxed::Box};
 
-use crate::traits::{LayoutProvider, TryInitialize, TryPinInitialize};
+mod thin;
+
+pub use thin::ThinBox;
+
+use crate::layout::{InitLayoutProvider, SliceLayoutProvider};
+use crate::traits::{HasLayoutProvider, LayoutProvider, SizeHint, TryInitialize, TryPinInitialize};
 
 /// An error type that for failure to emplace in a heap allocation a value
+///
+/// The `try_*` functions never call [`handle_alloc_error`](alloc::handle_alloc_error),
+/// so they are available with `no_global_oom_handling`, unlike the panicking functions
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AllocError<E> {
     /// Initialization failed
     Init(E),
@@ -19,23 +29,105 @@ pub enum AllocError<E> {
     Layout(LayoutError),
     /// The allocation failed with the given layout
     Alloc(Layout),
+    /// The layout provider gave a layout which doesn't match the layout of the value,
+    /// so the value can't be deallocated by a `Box`
+    LayoutMismatch {
+        /// the layout given by the layout provider
+        provided: Layout,
+        /// the layout of the value
+        required: Layout,
+    },
+}
+
+impl<E> AllocError<E> {
+    /// Map the initialization error, and keep all other errors the same
+    pub fn map_init<F>(self, f: impl FnOnce(E) -> F) -> AllocError<F> {
+        match self {
+            Self::Init(err) => AllocError::Init(f(err)),
+            Self::Layout(err) => AllocError::Layout(err),
+            Self::Alloc(layout) => AllocError::Alloc(layout),
+            Self::LayoutMismatch { provided, required } => {
+                AllocError::LayoutMismatch { provided, required }
+            }
+        }
+    }
+
+    /// The initialization error, if initialization failed
+    pub fn into_init_err(self) -> Option<E> {
+        match self {
+            Self::Init(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the allocator failed to allocate memory
+    pub fn is_alloc_error(&self) -> bool {
+        matches!(self, Self::Alloc(_))
+    }
+
+    /// The layout which the allocator failed to allocate
+    pub fn alloc_layout(&self) -> Option<Layout> {
+        match *self {
+            Self::Alloc(layout) => Some(layout),
+            _ => None,
+        }
+    }
+
+    /// Convert a failure to allocate memory into the allocator API's error type
+    #[cfg(feature = "allocator_api")]
+    pub fn alloc_error(&self) -> Option<core::alloc::AllocError> {
+        match self {
+            Self::Alloc(_) => Some(core::alloc::AllocError),
+            _ => None,
+        }
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for AllocError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Init(err) => write!(f, "failed to initialize value: {err}"),
+            Self::Layout(err) => write!(f, "could not compute layout for value: {err}"),
+            Self::Alloc(layout) => write!(f, "failed to allocate memory for {layout:?}"),
+            Self::LayoutMismatch { provided, required } => write!(
+                f,
+                "the provided layout {provided:?} doesn't match the layout of the value {required:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for AllocError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Init(err) => Some(err),
+            Self::Layout(err) => Some(err),
+            Self::Alloc(_) | Self::LayoutMismatch { .. } => None,
+        }
+    }
 }
 
 #[cold]
 #[inline(never)]
-fn handle<E: Debug>(err: &AllocError<E>) -> ! {
+#[cfg(not(no_global_oom_handling))]
+pub(crate) fn handle<E: Debug>(err: &AllocError<E>) -> ! {
     match err {
-        AllocError::Init(err) => panic!("Failed to initialize value: {err:?}"),
+        AllocError::Init(err) => crate::init_failed(err),
         AllocError::Layout(_) => panic!("Could not compute layout for value"),
         AllocError::Alloc(layout) => alloc::handle_alloc_error(*layout),
+        AllocError::LayoutMismatch { provided, required } => panic!(
+            "The provided layout {provided:?} doesn't match the layout of the value {required:?}"
+        ),
     }
 }
 
 /// create a new T, and initialize it in place
+#[cfg(not(no_global_oom_handling))]
 pub fn emplace<T: ?Sized, L, I>(provider: L, init: I) -> Box<T>
 where
     I: TryInitialize<T>,
-    L: LayoutProvider<T>,
+    L: LayoutProvider<T, I>,
     I::Error: Debug,
 {
     match try_emplace(provider, init) {
@@ -45,10 +137,11 @@ where
 }
 
 /// create a new T, and pin initialize it in place
+#[cfg(not(no_global_oom_handling))]
 pub fn emplace_pin<T: ?Sized, L, I>(provider: L, init: I) -> Pin<Box<T>>
 where
     I: TryPinInitialize<T>,
-    L: LayoutProvider<T>,
+    L: LayoutProvider<T, I>,
     I::Error: Debug,
 {
     match try_emplace_pin(provider, init) {
@@ -57,11 +150,280 @@ where
     }
 }
 
+/// create a new T, and initialize it in place, using the default layout provider for T
+///
+/// This is the one argument form of [`emplace`], for types which don't need extra
+/// information to compute their layout
+#[cfg(not(no_global_oom_handling))]
+pub fn new<T: ?Sized + HasLayoutProvider, I>(init: I) -> Box<T>
+where
+    I: TryInitialize<T>,
+    T::LayoutProvider: LayoutProvider<T, I>,
+    I::Error: Debug,
+{
+    emplace(T::layout_provider(), init)
+}
+
+/// create a new T, and pin initialize it in place, using the default layout provider for T
+#[cfg(not(no_global_oom_handling))]
+pub fn pin<T: ?Sized + HasLayoutProvider, I>(init: I) -> Pin<Box<T>>
+where
+    I: TryPinInitialize<T>,
+    T::LayoutProvider: LayoutProvider<T, I>,
+    I::Error: Debug,
+{
+    emplace_pin(T::layout_provider(), init)
+}
+
+/// create a new T, and attempt to initialize it in place, using the default layout provider for T
+pub fn try_new<T: ?Sized + HasLayoutProvider, I>(init: I) -> Result<Box<T>, AllocError<I::Error>>
+where
+    I: TryInitialize<T>,
+    T::LayoutProvider: LayoutProvider<T, I>,
+{
+    try_emplace(T::layout_provider(), init)
+}
+
+/// create a new T, and attempt to pin initialize it in place, using the default layout provider for T
+pub fn try_pin<T: ?Sized + HasLayoutProvider, I>(
+    init: I,
+) -> Result<Pin<Box<T>>, AllocError<I::Error>>
+where
+    I: TryPinInitialize<T>,
+    T::LayoutProvider: LayoutProvider<T, I>,
+{
+    try_emplace_pin(T::layout_provider(), init)
+}
+
+/// create a new sized T, and initialize it in place
+///
+/// This is [`new`] for sized types, and is sugar for `emplace(SizedLayoutProvider, init)`
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_sized<T, I>(init: I) -> Box<T>
+where
+    I: TryInitialize<T>,
+    I::Error: Debug,
+{
+    new(init)
+}
+
+/// create a new sized T, and pin initialize it in place
+///
+/// This is [`pin`] for sized types
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_sized_pin<T, I>(init: I) -> Pin<Box<T>>
+where
+    I: TryPinInitialize<T>,
+    I::Error: Debug,
+{
+    pin(init)
+}
+
+/// create a new sized T, and attempt to initialize it in place
+///
+/// This is [`try_new`] for sized types
+pub fn try_emplace_sized<T, I>(init: I) -> Result<Box<T>, AllocError<I::Error>>
+where
+    I: TryInitialize<T>,
+{
+    try_new(init)
+}
+
+/// create a new sized T, and attempt to pin initialize it in place
+///
+/// This is [`try_pin`] for sized types
+pub fn try_emplace_sized_pin<T, I>(init: I) -> Result<Pin<Box<T>>, AllocError<I::Error>>
+where
+    I: TryPinInitialize<T>,
+{
+    try_pin(init)
+}
+
+/// create a new slice of length `len`, and initialize it in place
+///
+/// This is sugar for `emplace(SliceLayoutProvider(len), init)`. Initializers which expect
+/// a specific length, like [`MoveSliceInit`](crate::slice::MoveSliceInit), check it against
+/// `len` and fail on a mismatch. If the initializer knows its own length, then
+/// [`emplace_hinted`] avoids passing the length at all
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_slice<T, I>(len: usize, init: I) -> Box<[T]>
+where
+    I: TryInitialize<[T]>,
+    I::Error: Debug,
+{
+    emplace(SliceLayoutProvider(len), init)
+}
+
+/// create a new slice of length `len`, and pin initialize it in place
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_slice_pin<T, I>(len: usize, init: I) -> Pin<Box<[T]>>
+where
+    I: TryPinInitialize<[T]>,
+    I::Error: Debug,
+{
+    emplace_pin(SliceLayoutProvider(len), init)
+}
+
+/// create a new slice of length `len`, and attempt to initialize it in place
+pub fn try_emplace_slice<T, I>(len: usize, init: I) -> Result<Box<[T]>, AllocError<I::Error>>
+where
+    I: TryInitialize<[T]>,
+{
+    try_emplace(SliceLayoutProvider(len), init)
+}
+
+/// create a new slice of length `len`, and attempt to pin initialize it in place
+pub fn try_emplace_slice_pin<T, I>(
+    len: usize,
+    init: I,
+) -> Result<Pin<Box<[T]>>, AllocError<I::Error>>
+where
+    I: TryPinInitialize<[T]>,
+{
+    try_emplace_pin(SliceLayoutProvider(len), init)
+}
+
+/// create a new array, and initialize it in place with a slice initializer
+///
+/// This avoids both moving the array through the stack and converting from a `Box<[T]>`
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_array<T, I, const N: usize>(init: I) -> Box<[T; N]>
+where
+    I: TryInitialize<[T]>,
+    I::Error: Debug,
+{
+    new(crate::array::ArrayInit::new(init))
+}
+
+/// create a new array, and initialize each element in place with a clone of `init`
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_array_each<T, I, const N: usize>(init: I) -> Box<[T; N]>
+where
+    I: TryInitialize<T> + Clone,
+    I::Error: Debug,
+{
+    new(crate::slice::SliceInit::new(init))
+}
+
+/// create a new array, and attempt to initialize it in place with a slice initializer
+pub fn try_emplace_array<T, I, const N: usize>(init: I) -> Result<Box<[T; N]>, AllocError<I::Error>>
+where
+    I: TryInitialize<[T]>,
+{
+    try_new(crate::array::ArrayInit::new(init))
+}
+
+/// create a new array, and attempt to initialize each element in place with a clone of `init`
+pub fn try_emplace_array_each<T, I, const N: usize>(
+    init: I,
+) -> Result<Box<[T; N]>, AllocError<I::Error>>
+where
+    I: TryInitialize<T> + Clone,
+{
+    try_new(crate::slice::SliceInit::new(init))
+}
+
+/// create a new T with all of it's bytes zeroed
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_zeroed<T: crate::zeroed::Zeroable>() -> Box<T> {
+    new(crate::zeroed::ZeroInit)
+}
+
+/// create a new slice of length `len` with all of it's bytes zeroed
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_zeroed_slice<T: crate::zeroed::Zeroable>(len: usize) -> Box<[T]> {
+    emplace_slice(len, crate::zeroed::ZeroInit)
+}
+
+/// create a new T, and initialize it in place with it's default value
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_default<T: Default>() -> Box<T> {
+    new(crate::func::InitFn::new(|uninit| {
+        uninit.write(T::default())
+    }))
+}
+
+/// create a new slice of length `len`, and initialize each element in place with it's default value
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_default_slice<T: Default>(len: usize) -> Box<[T]> {
+    emplace_slice(len, crate::array::ArrayFromFn::new(|_| T::default()))
+}
+
+/// create a new slice from the items of an iterator, and initialize it in place
+///
+/// The length of the slice is the length of the iterator, so this is like
+/// `iter.collect::<Vec<_>>().into_boxed_slice()` without the intermediate `Vec`.
+/// Any items past the length of the iterator are ignored
+///
+/// # Panics
+///
+/// If the iterator yields fewer items than it's length
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_from_iter<T, I>(iter: I) -> Box<[T]>
+where
+    I: IntoIterator<Item = T>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let iter = iter.into_iter();
+    emplace_slice(iter.len(), crate::slice::ExactSliceIterInit::new(iter))
+}
+
+/// create a new slice from an iterator of initializers, and attempt to initialize it in place
+///
+/// The length of the slice is the length of the iterator
+pub fn try_emplace_from_iter<T, E, I>(
+    iter: I,
+) -> Result<Box<[T]>, AllocError<crate::slice::SliceIterInitError<E>>>
+where
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator,
+    I::Item: TryInitialize<T, Error = E>,
+{
+    let iter = iter.into_iter();
+    try_emplace_slice(iter.len(), crate::slice::ExactSliceIterInit::new(iter))
+}
+
+/// create a new T, and initialize it in place, using the layout given by the initializer
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_hinted<T: ?Sized, I>(init: I) -> Box<T>
+where
+    I: TryInitialize<T> + SizeHint<T>,
+    I::Error: Debug,
+{
+    emplace(InitLayoutProvider, init)
+}
+
+/// create a new T, and pin initialize it in place, using the layout given by the initializer
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_pin_hinted<T: ?Sized, I>(init: I) -> Pin<Box<T>>
+where
+    I: TryPinInitialize<T> + SizeHint<T>,
+    I::Error: Debug,
+{
+    emplace_pin(InitLayoutProvider, init)
+}
+
+/// create a new T, and attempt to initialize it in place, using the layout given by the initializer
+pub fn try_emplace_hinted<T: ?Sized, I>(init: I) -> Result<Box<T>, AllocError<I::Error>>
+where
+    I: TryInitialize<T> + SizeHint<T>,
+{
+    try_emplace(InitLayoutProvider, init)
+}
+
+/// create a new T, and attempt to pin initialize it in place, using the layout given by the initializer
+pub fn try_emplace_pin_hinted<T: ?Sized, I>(init: I) -> Result<Pin<Box<T>>, AllocError<I::Error>>
+where
+    I: TryPinInitialize<T> + SizeHint<T>,
+{
+    try_emplace_pin(InitLayoutProvider, init)
+}
+
 /// create a new T, and attempt to initialize it in place
 pub fn try_emplace<T: ?Sized, L, I>(provider: L, init: I) -> Result<Box<T>, AllocError<I::Error>>
 where
     I: TryInitialize<T>,
-    L: LayoutProvider<T>,
+    L: LayoutProvider<T, I>,
 {
     struct RawAllocation {
         ptr: *mut u8,
@@ -70,6 +432,10 @@ where
 
     impl Drop for RawAllocation {
         fn drop(&mut self) {
+            if self.layout.size() == 0 {
+                return;
+            }
+
             // SAFETY: RawAllocation is only constructed with a ptr allocated from
             // the global allocator with the given layout. So it's safe to deallocate it
             // using the same layout
@@ -77,7 +443,7 @@ where
         }
     }
 
-    let layout = match provider.layout_for() {
+    let layout = match provider.layout_for(&init) {
         Ok(layout) => layout,
         Err(err) => return Err(AllocError::Layout(err)),
     };
@@ -90,7 +456,7 @@ where
     };
 
     let ptr = match NonNull::new(ptr) {
-        Some(ptr) => provider.cast_nonnull(ptr),
+        Some(ptr) => provider.cast_nonnull(&init, ptr),
         None => return Err(AllocError::Alloc(layout)),
     };
 
@@ -99,6 +465,15 @@ where
         layout,
     };
 
+    // SAFETY: the pointer came from the layout provider, so it has valid metadata for T
+    let required = unsafe { Layout::for_value_raw(ptr.as_ptr()) };
+    if required != layout {
+        return Err(AllocError::LayoutMismatch {
+            provided: layout,
+            required,
+        });
+    }
+
     // SAFETY: the pointer is allocated for T (`LayoutProvider`), and valid for
     // is valid for writes and reads (after writes)
     match unsafe { crate::raw::try_init_in_place(init, ptr.as_ptr()) } {
@@ -119,7 +494,7 @@ pub fn try_emplace_pin<T: ?Sized, L, I>(
 ) -> Result<Pin<Box<T>>, AllocError<I::Error>>
 where
     I: TryPinInitialize<T>,
-    L: LayoutProvider<T>,
+    L: LayoutProvider<T, I>,
 {
     struct RawAllocation {
         ptr: *mut u8,
@@ -128,6 +503,10 @@ where
 
     impl Drop for RawAllocation {
         fn drop(&mut self) {
+            if self.layout.size() == 0 {
+                return;
+            }
+
             // SAFETY: RawAllocation is only constructed with a ptr allocated from
             // the global allocator with the given layout. So it's safe to deallocate it
             // using the same layout
@@ -135,7 +514,7 @@ where
         }
     }
 
-    let layout = match provider.layout_for() {
+    let layout = match provider.layout_for(&init) {
         Ok(layout) => layout,
         Err(err) => return Err(AllocError::Layout(err)),
     };
@@ -148,7 +527,7 @@ where
     };
 
     let ptr = match NonNull::new(ptr) {
-        Some(ptr) => provider.cast_nonnull(ptr),
+        Some(ptr) => provider.cast_nonnull(&init, ptr),
         None => return Err(AllocError::Alloc(layout)),
     };
 
@@ -157,6 +536,15 @@ where
         layout,
     };
 
+    // SAFETY: the pointer came from the layout provider, so it has valid metadata for T
+    let required = unsafe { Layout::for_value_raw(ptr.as_ptr()) };
+    if required != layout {
+        return Err(AllocError::LayoutMismatch {
+            provided: layout,
+            required,
+        });
+    }
+
     // SAFETY: the pointer is allocated for T (`LayoutProvider`), and valid for
     // is valid for writes and reads (after writes)
     // the value is kept in the pinned type-state
@@ -174,3 +562,653 @@ where
     // invalidate the internal self references
     Ok(unsafe { core::mem::transmute(ptr) })
 }
+
+/// create a new string which copies `value`, without an intermediate `String`
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_str(value: &str) -> Box<str> {
+    emplace_hinted(crate::str::StrInit::new(value))
+}
+
+/// create a new string of `len` bytes from the given chars
+///
+/// # Panics
+///
+/// If the chars don't encode to exactly `len` bytes
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace_str_chars<C: IntoIterator<Item = char>>(len: usize, chars: C) -> Box<str> {
+    emplace(
+        crate::layout::StrLayoutProvider(len),
+        crate::str::CharsInit::new(chars),
+    )
+}
+
+/// create a new string of `len` bytes, and attempt to initialize it from the given chars
+pub fn try_emplace_str_chars<C: IntoIterator<Item = char>>(
+    len: usize,
+    chars: C,
+) -> Result<Box<str>, AllocError<crate::str::StrLengthError>> {
+    try_emplace(
+        crate::layout::StrLayoutProvider(len),
+        crate::str::CharsInit::new(chars),
+    )
+}
+
+/// Grow the slice to `new_len` elements, and initialize the new elements in place
+///
+/// The existing elements are moved into the new allocation, and then `tail_init` initializes the rest
+///
+/// # Panics
+///
+/// If `new_len` is less than the length of the slice
+#[cfg(not(no_global_oom_handling))]
+pub fn grow<T, I>(boxed: Box<[T]>, new_len: usize, tail_init: I) -> Box<[T]>
+where
+    I: TryInitialize<[T]>,
+    I::Error: Debug,
+{
+    match try_grow(boxed, new_len, tail_init) {
+        Ok(boxed) => boxed,
+        Err(ref err) => handle(err),
+    }
+}
+
+/// Grow the slice to `new_len` elements, and attempt to initialize the new elements in place
+///
+/// If growing the allocation or initializing the new elements fails, then the existing
+/// elements are dropped and the allocation is freed
+///
+/// # Panics
+///
+/// If `new_len` is less than the length of the slice
+pub fn try_grow<T, I>(
+    boxed: Box<[T]>,
+    new_len: usize,
+    tail_init: I,
+) -> Result<Box<[T]>, AllocError<I::Error>>
+where
+    I: TryInitialize<[T]>,
+{
+    struct Prefix<T> {
+        ptr: *mut T,
+        len: usize,
+        layout: Layout,
+    }
+
+    impl<T> Drop for Prefix<T> {
+        fn drop(&mut self) {
+            // SAFETY: the first `len` elements are initialized, and the allocation
+            // was allocated from the global allocator with the given layout
+            unsafe {
+                core::ptr::slice_from_raw_parts_mut(self.ptr, self.len).drop_in_place();
+                if self.layout.size() != 0 {
+                    alloc::dealloc(self.ptr.cast(), self.layout)
+                }
+            }
+        }
+    }
+
+    let len = boxed.len();
+    assert!(
+        new_len >= len,
+        "Tried to grow a slice of length {len} to a shorter length {new_len}"
+    );
+
+    let old_layout = Layout::for_value::<[T]>(&boxed);
+    let mut prefix = Prefix {
+        ptr: Box::into_raw(boxed).cast::<T>(),
+        len,
+        layout: old_layout,
+    };
+
+    let layout = Layout::array::<T>(new_len).map_err(AllocError::Layout)?;
+
+    // the new layout is at least as large as the old layout, so if it is zero sized
+    // then no allocation is needed
+    if layout.size() != 0 {
+        let ptr = if old_layout.size() == 0 {
+            // SAFETY: the layout has non-zero size
+            unsafe { alloc::alloc(layout) }
+        } else {
+            // SAFETY: the pointer was allocated with `old_layout`, and the new size is non-zero
+            // and doesn't overflow `isize` when rounded to the alignment (`Layout::array`)
+            unsafe { alloc::realloc(prefix.ptr.cast(), old_layout, layout.size()) }
+        };
+
+        if ptr.is_null() {
+            return Err(AllocError::Alloc(layout));
+        }
+
+        prefix.ptr = ptr.cast();
+        prefix.layout = layout;
+    }
+
+    // SAFETY: the allocation has room for `new_len` elements, so the tail is in bounds
+    let tail = unsafe { core::ptr::slice_from_raw_parts_mut(prefix.ptr.add(len), new_len - len) };
+
+    // SAFETY: the tail is allocated for `[T]` and is valid for writes and reads (after writes)
+    unsafe { crate::raw::try_init_in_place(tail_init, tail) }.map_err(AllocError::Init)?;
+
+    let ptr = prefix.ptr;
+    core::mem::forget(prefix);
+
+    // SAFETY: all `new_len` elements are now initialized, and the allocation was allocated
+    // from the global allocator with the layout of `[T]` with `new_len` elements
+    Ok(unsafe { Box::from_raw(core::ptr::slice_from_raw_parts_mut(ptr, new_len)) })
+}
+
+/// The error and untouched allocation returned by [`init_boxed`] and [`init_boxed_pin`]
+type UninitBoxError<E, T> = (E, Box<core::mem::MaybeUninit<T>>);
+
+/// Initialize the value of an existing allocation in place
+///
+/// If initialization fails, then the error and the untouched allocation are returned
+pub fn init_boxed<T, I>(
+    boxed: Box<core::mem::MaybeUninit<T>>,
+    init: I,
+) -> Result<Box<T>, UninitBoxError<I::Error, T>>
+where
+    I: TryInitialize<T>,
+{
+    let ptr = Box::into_raw(boxed);
+
+    // SAFETY: the pointer came from a `Box`, so it is allocated for `T`
+    // and valid for writes and reads (after writes)
+    match unsafe { crate::raw::try_init_in_place(init, ptr.cast::<T>()) } {
+        // SAFETY: the pointer is now initialized and allocated via the global allocator
+        Ok(()) => Ok(unsafe { Box::from_raw(ptr.cast::<T>()) }),
+        // SAFETY: the pointer came from `Box::into_raw`
+        Err(err) => Err((err, unsafe { Box::from_raw(ptr) })),
+    }
+}
+
+/// Pin initialize the value of an existing allocation in place
+///
+/// If initialization fails, then the error and the untouched allocation are returned
+pub fn init_boxed_pin<T, I>(
+    boxed: Box<core::mem::MaybeUninit<T>>,
+    init: I,
+) -> Result<Pin<Box<T>>, UninitBoxError<I::Error, T>>
+where
+    I: TryPinInitialize<T>,
+{
+    let ptr = Box::into_raw(boxed);
+
+    // SAFETY: the pointer came from a `Box`, so it is allocated for `T`
+    // and valid for writes and reads (after writes)
+    // the value is kept in the pinned type-state
+    match unsafe { crate::raw::try_pin_init_in_place(init, ptr.cast::<T>()) } {
+        // SAFETY: the pointer is now initialized and allocated via the global allocator
+        // raw pointers have the same layout as Box
+        // we avoid `Box::into_pin` for the same reason as `try_emplace_pin`
+        Ok(()) => Ok(unsafe { core::mem::transmute::<*mut T, Pin<Box<T>>>(ptr.cast::<T>()) }),
+        // SAFETY: the pointer came from `Box::into_raw`
+        Err(err) => Err((err, unsafe { Box::from_raw(ptr) })),
+    }
+}
+
+/// Drop the value in the box, and re-initialize the allocation in place
+///
+/// This reuses the allocation instead of freeing it and allocating a new one.
+/// If initialization fails, then the error and the (now uninitialized) allocation are returned
+pub fn reinit<T, I>(boxed: Box<T>, init: I) -> Result<Box<T>, UninitBoxError<I::Error, T>>
+where
+    I: TryInitialize<T>,
+{
+    init_boxed(drop_in_box(boxed), init)
+}
+
+/// Drop the pinned value in the box, and pin re-initialize the allocation in place
+///
+/// The old value is dropped before the allocation is reused, so this upholds the pinning guarantees.
+/// If initialization fails, then the error and the (now uninitialized) allocation are returned
+pub fn reinit_pin<T, I>(
+    boxed: Pin<Box<T>>,
+    init: I,
+) -> Result<Pin<Box<T>>, UninitBoxError<I::Error, T>>
+where
+    I: TryPinInitialize<T>,
+{
+    // SAFETY: the value is dropped in place, and is never moved
+    init_boxed_pin(
+        drop_in_box(unsafe { Pin::into_inner_unchecked(boxed) }),
+        init,
+    )
+}
+
+/// Drop the value in the box, without freeing the allocation
+fn drop_in_box<T>(boxed: Box<T>) -> Box<core::mem::MaybeUninit<T>> {
+    let ptr = Box::into_raw(boxed);
+    // SAFETY: the pointer came from `Box::into_raw`, so it is valid to drop, and
+    // `MaybeUninit<T>` has the same layout as `T`. If dropping panics, then
+    // the allocation is leaked, which is safe
+    unsafe {
+        ptr.drop_in_place();
+        Box::from_raw(ptr.cast())
+    }
+}
+
+/// The error and untouched allocation returned by [`init_boxed_slice`]
+type UninitBoxSliceError<E, T> = (E, Box<[core::mem::MaybeUninit<T>]>);
+
+/// Initialize the elements of an existing slice allocation in place
+///
+/// If initialization fails, then the error and the untouched allocation are returned
+pub fn init_boxed_slice<T, I>(
+    boxed: Box<[core::mem::MaybeUninit<T>]>,
+    init: I,
+) -> Result<Box<[T]>, UninitBoxSliceError<I::Error, T>>
+where
+    I: TryInitialize<[T]>,
+{
+    let ptr = Box::into_raw(boxed);
+
+    // SAFETY: the pointer came from a `Box`, so it is allocated for `[T]`
+    // and valid for writes and reads (after writes)
+    match unsafe { crate::raw::try_init_in_place(init, ptr as *mut [T]) } {
+        // SAFETY: the pointer is now initialized and allocated via the global allocator
+        Ok(()) => Ok(unsafe { Box::from_raw(ptr as *mut [T]) }),
+        // SAFETY: the pointer came from `Box::into_raw`
+        Err(err) => Err((err, unsafe { Box::from_raw(ptr) })),
+    }
+}
+
+/// Allocate and initialize a T in the given allocator
+///
+/// If `exact` is set, then the provider's layout must be the layout of the value, because
+/// the allocation will be deallocated with the layout of the value (for example by a `Box`)
+///
+/// # Safety
+///
+/// `init_in_place` must initialize the pointer if it returns `Ok`
+#[cfg(feature = "allocator_api")]
+pub(crate) unsafe fn try_emplace_raw_in<T: ?Sized, A: core::alloc::Allocator, L, I, E>(
+    alloc: &A,
+    provider: L,
+    init: I,
+    exact: bool,
+    init_in_place: impl FnOnce(I, *mut T) -> Result<(), E>,
+) -> Result<NonNull<T>, AllocError<E>>
+where
+    L: LayoutProvider<T, I>,
+{
+    struct RawAllocation<'a, A: core::alloc::Allocator> {
+        alloc: &'a A,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    }
+
+    impl<A: core::alloc::Allocator> Drop for RawAllocation<'_, A> {
+        fn drop(&mut self) {
+            // SAFETY: RawAllocation is only constructed with a ptr allocated from
+            // the allocator with the given layout. So it's safe to deallocate it
+            // using the same layout
+            unsafe { self.alloc.deallocate(self.ptr, self.layout) }
+        }
+    }
+
+    let layout = match provider.layout_for(&init) {
+        Ok(layout) => layout,
+        Err(err) => return Err(AllocError::Layout(err)),
+    };
+
+    let raw = match alloc.allocate(layout) {
+        Ok(ptr) => ptr.cast::<u8>(),
+        Err(core::alloc::AllocError) => return Err(AllocError::Alloc(layout)),
+    };
+
+    let allocation = RawAllocation {
+        alloc,
+        ptr: raw,
+        layout,
+    };
+
+    let ptr = provider.cast_nonnull(&init, raw);
+
+    // SAFETY: the pointer came from the layout provider, so it has valid metadata for T
+    let required = unsafe { Layout::for_value_raw(ptr.as_ptr()) };
+    if exact && required != layout {
+        return Err(AllocError::LayoutMismatch {
+            provided: layout,
+            required,
+        });
+    }
+
+    match init_in_place(init, ptr.as_ptr()) {
+        Ok(()) => (),
+        Err(err) => return Err(AllocError::Init(err)),
+    }
+
+    core::mem::forget(allocation);
+
+    Ok(ptr)
+}
+
+/// create a new T in the given allocator, and initialize it in place
+#[cfg(all(feature = "allocator_api", not(no_global_oom_handling)))]
+pub fn emplace_in<T: ?Sized, A: core::alloc::Allocator, L, I>(
+    alloc: A,
+    provider: L,
+    init: I,
+) -> Box<T, A>
+where
+    I: TryInitialize<T>,
+    L: LayoutProvider<T, I>,
+    I::Error: Debug,
+{
+    match try_emplace_in(alloc, provider, init) {
+        Ok(boxed) => boxed,
+        Err(ref err) => handle(err),
+    }
+}
+
+/// create a new T in the given allocator, and pin initialize it in place
+#[cfg(all(feature = "allocator_api", not(no_global_oom_handling)))]
+pub fn emplace_pin_in<T: ?Sized, A: core::alloc::Allocator + 'static, L, I>(
+    alloc: A,
+    provider: L,
+    init: I,
+) -> Pin<Box<T, A>>
+where
+    I: TryPinInitialize<T>,
+    L: LayoutProvider<T, I>,
+    I::Error: Debug,
+{
+    match try_emplace_pin_in(alloc, provider, init) {
+        Ok(boxed) => boxed,
+        Err(ref err) => handle(err),
+    }
+}
+
+/// create a new T in the given allocator, and attempt to initialize it in place
+#[cfg(feature = "allocator_api")]
+pub fn try_emplace_in<T: ?Sized, A: core::alloc::Allocator, L, I>(
+    alloc: A,
+    provider: L,
+    init: I,
+) -> Result<Box<T, A>, AllocError<I::Error>>
+where
+    I: TryInitialize<T>,
+    L: LayoutProvider<T, I>,
+{
+    // SAFETY: `try_init_in_place` initializes the pointer if it returns `Ok`
+    // and the pointer is allocated for T (`LayoutProvider`), and valid for
+    // is valid for writes and reads (after writes)
+    let ptr = unsafe {
+        try_emplace_raw_in(&alloc, provider, init, true, |init, ptr| {
+            crate::raw::try_init_in_place(init, ptr)
+        })?
+    };
+
+    // SAFETY: the pointer is now initialized and allocated via `alloc`
+    Ok(unsafe { Box::from_raw_in(ptr.as_ptr(), alloc) })
+}
+
+/// create a new T in the given allocator, and attempt to pin initialize it in place
+///
+/// The allocator must be `'static`, because otherwise the allocator could be
+/// freed before the value is dropped, which would violate the pinning guarantees
+#[cfg(feature = "allocator_api")]
+pub fn try_emplace_pin_in<T: ?Sized, A: core::alloc::Allocator + 'static, L, I>(
+    alloc: A,
+    provider: L,
+    init: I,
+) -> Result<Pin<Box<T, A>>, AllocError<I::Error>>
+where
+    I: TryPinInitialize<T>,
+    L: LayoutProvider<T, I>,
+{
+    // SAFETY: `try_pin_init_in_place` initializes the pointer if it returns `Ok`
+    // and the pointer is allocated for T (`LayoutProvider`), and valid for
+    // is valid for writes and reads (after writes)
+    // the value is kept in the pinned type-state
+    let ptr = unsafe {
+        try_emplace_raw_in(&alloc, provider, init, true, |init, ptr| {
+            crate::raw::try_pin_init_in_place(init, ptr)
+        })?
+    };
+
+    // SAFETY: the pointer is now initialized and allocated via `alloc`
+    // and the value was pin initialized, so it must stay pinned
+    Ok(unsafe { Pin::new_unchecked(Box::from_raw_in(ptr.as_ptr(), alloc)) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_emplace_hinted() {
+        let boxed: Box<[u32]> = emplace_hinted(crate::slice::ExactSliceIterInit::new(0..4));
+        assert_eq!(*boxed, [0, 1, 2, 3]);
+
+        let boxed: Box<str> = emplace_hinted(crate::str::StrInit::new("hello"));
+        assert_eq!(&*boxed, "hello");
+    }
+
+    #[test]
+    fn test_new() {
+        let boxed: Box<[u32; 3]> = new(crate::array::ArrayFromFn::new(|i| i as u32));
+        assert_eq!(*boxed, [0, 1, 2]);
+
+        let boxed: Pin<Box<u8>> = pin(3);
+        assert_eq!(*boxed, 3);
+
+        let err = try_new::<[u32; 3], _>(crate::slice::SliceIterInit::new(0..2));
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "allocator_api")]
+    fn test_emplace_in() {
+        use crate::layout::{SizedLayoutProvider, SliceLayoutProvider};
+        use ::alloc::alloc::Global;
+
+        let boxed: Box<[u32], Global> = emplace_in(
+            Global,
+            SliceLayoutProvider(3),
+            crate::slice::RepeatInit::new(7),
+        );
+        assert_eq!(*boxed, [7, 7, 7]);
+
+        let boxed: Pin<Box<u8, Global>> = emplace_pin_in(Global, SizedLayoutProvider, 5);
+        assert_eq!(*boxed, 5);
+    }
+
+    #[test]
+    fn test_init_boxed() {
+        let boxed =
+            init_boxed::<[usize; 4], _>(Box::new_uninit(), crate::array::ArrayFromFn::new(|i| i));
+        assert_eq!(*boxed.ok().unwrap(), [0, 1, 2, 3]);
+
+        let err =
+            init_boxed::<[u32; 4], _>(Box::new_uninit(), crate::slice::SliceIterInit::new(0..2));
+        let (_, uninit) = err.err().unwrap();
+        let boxed = init_boxed_pin(uninit, [1; 4]);
+        assert_eq!(*boxed.ok().unwrap(), [1; 4]);
+    }
+
+    #[test]
+    fn test_init_boxed_slice() {
+        let boxed = init_boxed_slice(
+            Box::new_uninit_slice(3),
+            crate::slice::SliceIterInit::new(0..),
+        );
+        assert_eq!(*boxed.ok().unwrap(), [0, 1, 2]);
+
+        let err = init_boxed_slice::<u32, _>(
+            Box::new_uninit_slice(3),
+            crate::slice::SliceIterInit::new(0..2),
+        );
+        let (_, uninit) = err.err().unwrap();
+        assert_eq!(uninit.len(), 3);
+    }
+
+    #[test]
+    fn test_emplace_str() {
+        assert_eq!(&*emplace_str("hello"), "hello");
+        assert_eq!(&*emplace_str_chars(3, "abc".chars()), "abc");
+        assert!(try_emplace_str_chars(2, "abc".chars()).is_err());
+    }
+
+    #[test]
+    fn test_emplace_sized() {
+        let boxed = emplace_sized::<[u32; 3], _>(crate::array::ArrayFromFn::new(|i| i as u32));
+        assert_eq!(*boxed, [0, 1, 2]);
+
+        let boxed = emplace_sized_pin::<u8, _>(3);
+        assert_eq!(*boxed, 3);
+
+        let err = try_emplace_sized_pin::<u8, _>(crate::func::TryPinInitFn::new(|_| Err(())));
+        assert!(matches!(err, Err(AllocError::Init(()))));
+    }
+
+    #[test]
+    fn test_emplace_slice() {
+        let boxed = emplace_slice(3, crate::slice::SliceIterInit::new(0..));
+        assert_eq!(*boxed, [0, 1, 2]);
+
+        let boxed = emplace_slice_pin(2, crate::slice::RepeatInit::new(1u8));
+        assert_eq!(*boxed, [1, 1]);
+
+        let err = try_emplace_slice::<u32, _>(3, crate::slice::SliceIterInit::new(0..2));
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+
+    #[test]
+    fn test_emplace_array() {
+        let boxed: Box<[u32; 3]> = emplace_array(crate::slice::SliceIterInit::new(0..));
+        assert_eq!(*boxed, [0, 1, 2]);
+
+        let boxed: Box<[u8; 1024]> = emplace_array_each(7);
+        assert!(boxed.iter().all(|&x| x == 7));
+
+        let err = try_emplace_array::<u32, _, 3>(crate::slice::SliceIterInit::new(0..2));
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+
+    #[test]
+    fn test_emplace_zeroed_default() {
+        assert_eq!(*emplace_zeroed::<[u64; 8]>(), [0; 8]);
+        assert_eq!(*emplace_zeroed_slice::<u16>(3), [0; 3]);
+        assert_eq!(*emplace_default::<(u8, bool)>(), (0, false));
+        assert_eq!(
+            *emplace_default_slice::<::alloc::string::String>(2),
+            [
+                ::alloc::string::String::new(),
+                ::alloc::string::String::new()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_emplace_from_iter() {
+        let boxed = emplace_from_iter((0..4).map(|x| x * 2));
+        assert_eq!(*boxed, [0, 2, 4, 6]);
+
+        let boxed = emplace_from_iter(::alloc::vec![::alloc::string::String::from("a")]);
+        assert_eq!(*boxed, ["a"]);
+
+        let boxed = try_emplace_from_iter::<u8, _, _>([1, 2, 3]);
+        assert_eq!(*boxed.ok().unwrap(), [1, 2, 3]);
+
+        let err = try_emplace_from_iter::<u8, _, _>(
+            [Ok(1), Err(()), Ok(3)]
+                .map(|x| crate::func::TryInitFn::new(move |u| x.map(|x| u.write(x)))),
+        );
+        assert!(matches!(
+            err,
+            Err(AllocError::Init(crate::slice::SliceIterInitError::Init {
+                index: 1,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_alloc_error() {
+        let err = try_new::<[u32; 3], _>(crate::slice::SliceIterInit::new(0..2))
+            .err()
+            .unwrap()
+            .map_init(|err| err.initialized());
+        assert_eq!(err, AllocError::Init(2));
+        assert_eq!(err.into_init_err(), Some(2));
+
+        let err = AllocError::<u8>::Alloc(Layout::new::<u32>());
+        assert_eq!(err.into_init_err(), None);
+        assert_eq!(
+            ::alloc::format!("{}", AllocError::Init("bad")),
+            "failed to initialize value: bad"
+        );
+    }
+
+    #[test]
+    fn test_reinit() {
+        let boxed = new::<[::alloc::string::String; 2], _>(crate::slice::SliceInit::new(
+            crate::func::InitFn::new(|u| u.write(::alloc::string::String::from("a"))),
+        ));
+        let ptr = &*boxed as *const _;
+
+        let boxed = reinit(
+            boxed,
+            core::array::from_fn(|_| ::alloc::string::String::from("b")),
+        )
+        .ok()
+        .unwrap();
+        assert_eq!(*boxed, ["b", "b"]);
+        assert_eq!(&*boxed as *const _, ptr);
+
+        let (_, uninit) = reinit(
+            boxed,
+            crate::slice::SliceIterInit::new(core::iter::empty::<::alloc::string::String>()),
+        )
+        .err()
+        .unwrap();
+        let boxed = init_boxed_pin(
+            uninit,
+            core::array::from_fn(|_| ::alloc::string::String::new()),
+        );
+        let boxed = reinit_pin(boxed.ok().unwrap(), core::array::from_fn(|_| "c".into()));
+        assert_eq!(*boxed.ok().unwrap(), ["c", "c"]);
+    }
+
+    #[test]
+    fn test_grow() {
+        use ::alloc::string::{String, ToString};
+
+        let boxed = emplace_from_iter(["a".to_string()]);
+        let boxed = grow(
+            boxed,
+            3,
+            crate::slice::SliceIterInit::new((1..).map(|i| i.to_string())),
+        );
+        assert_eq!(*boxed, ["a", "1", "2"]);
+
+        let boxed = grow(
+            Box::<[String]>::default(),
+            1,
+            crate::slice::RepeatInit::new(String::new()),
+        );
+        assert_eq!(*boxed, [""]);
+
+        let boxed = grow(
+            boxed,
+            1,
+            crate::slice::SliceIterInit::new(core::iter::empty::<String>()),
+        );
+        assert_eq!(*boxed, [""]);
+
+        let boxed = grow(
+            Box::new([(), ()]) as Box<[()]>,
+            4,
+            crate::slice::RepeatInit::new(()),
+        );
+        assert_eq!(boxed.len(), 4);
+
+        let err = try_grow(
+            boxed,
+            6,
+            crate::slice::SliceIterInit::new(core::iter::once(())),
+        );
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+}
@@ -0,0 +1,284 @@
+use core::{
+    alloc::Layout,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    ptr::{NonNull, Pointee},
+};
+
+use ::alloc::alloc;
+
+use super::AllocError;
+use crate::traits::{LayoutProvider, TryInitialize, TryPinInitialize};
+
+/// A pointer sized box which stores the metadata of `T` inline in the allocation
+///
+/// This allows storing trait objects and slices in pointer sized fields. The metadata
+/// is stored directly before the value, so the allocation looks like `(Metadata, T)`
+pub struct ThinBox<T: ?Sized> {
+    /// points to the value, the metadata is stored directly before it
+    ptr: NonNull<u8>,
+    _ty: PhantomData<T>,
+}
+
+// SAFETY: ThinBox owns a T, just like a Box
+unsafe impl<T: ?Sized + Send> Send for ThinBox<T> {}
+// SAFETY: ThinBox owns a T, just like a Box
+unsafe impl<T: ?Sized + Sync> Sync for ThinBox<T> {}
+
+impl<T: ?Sized> Unpin for ThinBox<T> {}
+
+/// The layout of the whole allocation, and the offset of the value in it
+fn allocation_layout<T: ?Sized>(
+    value: Layout,
+) -> Result<(Layout, usize), core::alloc::LayoutError> {
+    let (layout, offset) = Layout::new::<<T as Pointee>::Metadata>().extend(value)?;
+    Ok((layout.pad_to_align(), offset))
+}
+
+impl<T: ?Sized> ThinBox<T> {
+    /// create a new T, and initialize it in place
+    #[cfg(not(no_global_oom_handling))]
+    pub fn emplace<L, I>(provider: L, init: I) -> Self
+    where
+        I: TryInitialize<T>,
+        L: LayoutProvider<T, I>,
+        I::Error: fmt::Debug,
+    {
+        match Self::try_emplace(provider, init) {
+            Ok(boxed) => boxed,
+            Err(ref err) => super::handle(err),
+        }
+    }
+
+    /// create a new T, and pin initialize it in place
+    #[cfg(not(no_global_oom_handling))]
+    pub fn emplace_pin<L, I>(provider: L, init: I) -> Pin<Self>
+    where
+        I: TryPinInitialize<T>,
+        L: LayoutProvider<T, I>,
+        I::Error: fmt::Debug,
+    {
+        match Self::try_emplace_pin(provider, init) {
+            Ok(boxed) => boxed,
+            Err(ref err) => super::handle(err),
+        }
+    }
+
+    /// create a new T, and attempt to initialize it in place
+    pub fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+    where
+        I: TryInitialize<T>,
+        L: LayoutProvider<T, I>,
+    {
+        // SAFETY: the value is initialized with `try_init_in_place`
+        unsafe {
+            Self::try_emplace_with(provider, init, |init, ptr| {
+                crate::raw::try_init_in_place(init, ptr)
+            })
+        }
+    }
+
+    /// create a new T, and attempt to pin initialize it in place
+    pub fn try_emplace_pin<L, I>(provider: L, init: I) -> Result<Pin<Self>, AllocError<I::Error>>
+    where
+        I: TryPinInitialize<T>,
+        L: LayoutProvider<T, I>,
+    {
+        // SAFETY: the value is pin initialized with `try_pin_init_in_place`
+        let boxed = unsafe {
+            Self::try_emplace_with(provider, init, |init, ptr| {
+                crate::raw::try_pin_init_in_place(init, ptr)
+            })
+        }?;
+
+        // SAFETY: the value is never moved out of it's allocation
+        Ok(unsafe { Pin::new_unchecked(boxed) })
+    }
+
+    /// # Safety
+    ///
+    /// `init_in_place` must have the same contract as [`crate::raw::try_init_in_place`]
+    unsafe fn try_emplace_with<L, I, E>(
+        provider: L,
+        init: I,
+        init_in_place: impl FnOnce(I, *mut T) -> Result<(), E>,
+    ) -> Result<Self, AllocError<E>>
+    where
+        L: LayoutProvider<T, I>,
+    {
+        struct RawAllocation {
+            ptr: *mut u8,
+            layout: Layout,
+        }
+
+        impl Drop for RawAllocation {
+            fn drop(&mut self) {
+                if self.layout.size() == 0 {
+                    return;
+                }
+
+                // SAFETY: RawAllocation is only constructed with a ptr allocated from
+                // the global allocator with the given layout. So it's safe to deallocate it
+                // using the same layout
+                unsafe { alloc::dealloc(self.ptr, self.layout) }
+            }
+        }
+
+        let value_layout = provider.layout_for(&init).map_err(AllocError::Layout)?;
+        let (layout, offset) = allocation_layout::<T>(value_layout).map_err(AllocError::Layout)?;
+
+        let base = if layout.size() == 0 {
+            layout.align() as *mut u8
+        } else {
+            // SAFETY: the layout has non-zero size
+            unsafe { alloc::alloc(layout) }
+        };
+
+        if base.is_null() {
+            return Err(AllocError::Alloc(layout));
+        }
+
+        let alloc = RawAllocation { ptr: base, layout };
+
+        // SAFETY: the offset is in bounds of the allocation
+        let value = unsafe { NonNull::new_unchecked(base.add(offset)) };
+        let ptr = provider.cast_nonnull(&init, value);
+
+        // SAFETY: the pointer came from the layout provider, so it has valid metadata for T
+        let required = unsafe { Layout::for_value_raw(ptr.as_ptr()) };
+        if required != value_layout {
+            return Err(AllocError::LayoutMismatch {
+                provided: value_layout,
+                required,
+            });
+        }
+
+        // SAFETY: the metadata is stored directly before the value, and the allocation
+        // has room for it there. `allocation_layout` ensures that it's aligned
+        unsafe {
+            Self::metadata_ptr(value).write(core::ptr::metadata(ptr.as_ptr()));
+        }
+
+        // the pointer is allocated for T (`LayoutProvider`), and is valid for
+        // writes and reads (after writes)
+        init_in_place(init, ptr.as_ptr()).map_err(AllocError::Init)?;
+
+        core::mem::forget(alloc);
+
+        Ok(Self {
+            ptr: value,
+            _ty: PhantomData,
+        })
+    }
+
+    fn metadata_ptr(value: NonNull<u8>) -> *mut <T as Pointee>::Metadata {
+        value
+            .as_ptr()
+            .wrapping_sub(core::mem::size_of::<<T as Pointee>::Metadata>())
+            .cast()
+    }
+
+    /// Get a raw pointer to the value
+    pub fn as_ptr(&self) -> *mut T {
+        // SAFETY: the metadata was written when the ThinBox was created
+        let metadata = unsafe { Self::metadata_ptr(self.ptr).read() };
+        core::ptr::from_raw_parts_mut(self.ptr.as_ptr(), metadata)
+    }
+}
+
+impl<T: ?Sized> Drop for ThinBox<T> {
+    fn drop(&mut self) {
+        let ptr = self.as_ptr();
+
+        // SAFETY: the value was initialized when the ThinBox was created, and
+        // the layout was computed successfully then
+        unsafe {
+            let value_layout = Layout::for_value_raw(ptr);
+            let (layout, offset) = allocation_layout::<T>(value_layout).unwrap_unchecked();
+
+            ptr.drop_in_place();
+
+            if layout.size() != 0 {
+                alloc::dealloc(self.ptr.as_ptr().sub(offset), layout)
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for ThinBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the value is initialized and owned by the ThinBox
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for ThinBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the value is initialized and uniquely owned by the ThinBox
+        unsafe { &mut *self.as_ptr() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ThinBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for ThinBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout::SliceLayoutProvider, slice::SliceIterInit};
+
+    #[test]
+    fn test_thin_box() {
+        assert_eq!(
+            core::mem::size_of::<ThinBox<[u64]>>(),
+            core::mem::size_of::<usize>()
+        );
+
+        let mut boxed = ThinBox::<[u64]>::emplace(SliceLayoutProvider(3), SliceIterInit::new(0..));
+        assert_eq!(*boxed, [0, 1, 2]);
+        boxed[1] = 10;
+        assert_eq!(*boxed, [0, 10, 2]);
+
+        let boxed = ThinBox::<[u8]>::emplace(SliceLayoutProvider(0), SliceIterInit::new(0..));
+        assert!(boxed.is_empty());
+
+        let boxed = ThinBox::<u8>::emplace(crate::layout::SizedLayoutProvider, 3);
+        assert_eq!(*boxed, 3);
+
+        let boxed = ThinBox::<()>::emplace(crate::layout::SizedLayoutProvider, ());
+        assert_eq!(*boxed, ());
+
+        let err = ThinBox::<[u8]>::try_emplace(SliceLayoutProvider(4), SliceIterInit::new(0..2));
+        assert!(matches!(err, Err(AllocError::Init(_))));
+    }
+
+    #[test]
+    fn test_thin_box_dyn() {
+        use crate::layout::DynLayoutProvider;
+
+        let init = crate::func::InitFn::new(|mut uninit: crate::Uninit<dyn fmt::Display>| {
+            // SAFETY: the slot was allocated with the vtable of `u16`
+            let slot = unsafe { crate::Uninit::from_raw(uninit.as_mut_ptr().cast::<u16>()) };
+            slot.write(42).into_raw();
+            // SAFETY: the slot was initialized with a `u16`, which matches the vtable
+            unsafe { uninit.assume_init() }
+        });
+
+        let boxed =
+            ThinBox::<dyn fmt::Display>::emplace(DynLayoutProvider::for_type::<u16>(), init);
+        assert_eq!(::alloc::format!("{boxed}"), "42");
+    }
+}
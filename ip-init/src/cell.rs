@@ -0,0 +1,565 @@
+//! cells which hand out their storage to initializers
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicBool, AtomicU8, Ordering},
+};
+
+use crate::{
+    traits::{Initialize, TryInitialize},
+    Init, Uninit,
+};
+
+/// Static storage for a `T`, which can be initialized in place exactly once
+///
+/// This is meant to be used in `static` items, to initialize large values at runtime
+/// without `static mut` or the heap. The storage is handed out at most once, so
+/// the value is never dropped.
+///
+/// ```
+/// use ip_init::cell::StaticUninit;
+///
+/// static TABLE: StaticUninit<[u32; 256]> = StaticUninit::new();
+///
+/// let table = TABLE.init_with(ip_init::array::ArrayFromFn::new(|i| i as u32 * 2));
+/// assert_eq!(table[3], 6);
+/// assert!(TABLE.take().is_none());
+/// ```
+pub struct StaticUninit<T> {
+    taken: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: the value is only accessible through the unique `Uninit` handed out by `take`,
+// so it may be sent to whichever thread takes it
+unsafe impl<T: Send> Sync for StaticUninit<T> {}
+
+/// The error for when a [`StaticUninit`] can't be initialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticInitError<E> {
+    /// The storage was already taken
+    AlreadyTaken,
+    /// Initialization failed, the storage can't be taken again
+    Init(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for StaticInitError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlreadyTaken => f.write_str("the static storage was already taken"),
+            Self::Init(err) => write!(f, "failed to initialize value: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for StaticInitError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AlreadyTaken => None,
+            Self::Init(err) => Some(err),
+        }
+    }
+}
+
+impl<T> StaticUninit<T> {
+    /// Create new uninitialized storage
+    pub const fn new() -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Take the uninitialized storage, this returns `Some` at most once
+    pub fn take(&'static self) -> Option<Uninit<'static, T>> {
+        if self.taken.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+
+        // SAFETY: the flag was just set, so this is the only reference to the value
+        Some(Uninit::from_maybe_uninit(unsafe { &mut *self.value.get() }))
+    }
+
+    /// Take the storage, and attempt to initialize it in place
+    ///
+    /// If initialization fails, then the storage can't be taken again
+    pub fn try_init_with<I: TryInitialize<T>>(
+        &'static self,
+        init: I,
+    ) -> Result<&'static mut T, StaticInitError<I::Error>> {
+        let uninit = self.take().ok_or(StaticInitError::AlreadyTaken)?;
+        match uninit.try_init(init) {
+            Ok(init) => Ok(init.leak()),
+            Err(err) => Err(StaticInitError::Init(err)),
+        }
+    }
+
+    /// Take the storage, and initialize it in place
+    ///
+    /// # Panics
+    ///
+    /// If the storage was already taken
+    pub fn init_with<I: Initialize<T>>(&'static self, init: I) -> &'static mut T {
+        match self.take() {
+            Some(uninit) => uninit.init(init).leak(),
+            None => panic!("the static storage was already taken"),
+        }
+    }
+
+    /// Returns `true` if the storage was already taken
+    pub fn is_taken(&self) -> bool {
+        self.taken.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Default for StaticUninit<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> core::fmt::Debug for StaticUninit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticUninit")
+            .field("taken", &self.is_taken())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A single-threaded cell which is initialized in place at most once
+///
+/// This is like [`OnceCell`](core::cell::OnceCell), but the value is initialized in place by an initializer
+pub struct OnceInit<T> {
+    state: Cell<u8>,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> OnceInit<T> {
+    /// Create a new uninitialized cell
+    pub const fn new() -> Self {
+        Self {
+            state: Cell::new(INCOMPLETE),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get the value, if it was initialized
+    pub fn get(&self) -> Option<&T> {
+        if self.state.get() == COMPLETE {
+            // SAFETY: the state is complete, so the value is initialized
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Get the value mutably, if it was initialized
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.state.get() == COMPLETE {
+            // SAFETY: the state is complete, so the value is initialized
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Get the value, or initialize it in place with `init` if the cell is empty
+    ///
+    /// If the cell is already initialized, then `init` is dropped without running
+    ///
+    /// # Panics
+    ///
+    /// If this is called from the initializer of the same cell
+    pub fn get_or_init_with<I: Initialize<T>>(&self, init: I) -> &T {
+        match self.get_or_try_init_with(init) {
+            Ok(value) => value,
+            Err(err) => match err {},
+        }
+    }
+
+    /// Get the value, or attempt to initialize it in place with `init` if the cell is empty
+    ///
+    /// If initialization fails, then the cell stays empty. If the cell is already
+    /// initialized, then `init` is dropped without running
+    ///
+    /// # Panics
+    ///
+    /// If this is called from the initializer of the same cell
+    pub fn get_or_try_init_with<I: TryInitialize<T>>(&self, init: I) -> Result<&T, I::Error> {
+        struct Reset<'a>(&'a Cell<u8>);
+
+        impl Drop for Reset<'_> {
+            fn drop(&mut self) {
+                self.0.set(INCOMPLETE);
+            }
+        }
+
+        match self.state.get() {
+            // SAFETY: the state is complete, so the value is initialized
+            COMPLETE => return Ok(unsafe { (*self.value.get()).assume_init_ref() }),
+            RUNNING => panic!("reentrant initialization of `OnceInit`"),
+            _ => (),
+        }
+
+        self.state.set(RUNNING);
+        let reset = Reset(&self.state);
+
+        // SAFETY: the state is running, so this is the only reference to the value
+        let uninit = Uninit::from_maybe_uninit(unsafe { &mut *self.value.get() });
+        // the value is owned by `self`, and is dropped when `self` is dropped
+        let value = uninit.try_init(init)?.leak();
+
+        core::mem::forget(reset);
+        self.state.set(COMPLETE);
+
+        Ok(value)
+    }
+
+    /// Take the value out of the cell, leaving it empty
+    pub fn take(&mut self) -> Option<T> {
+        core::mem::take(self).into_inner()
+    }
+
+    /// Get the value, if it was initialized
+    pub fn into_inner(self) -> Option<T> {
+        let this = core::mem::ManuallyDrop::new(self);
+        if this.state.get() == COMPLETE {
+            // SAFETY: the state is complete, so the value is initialized
+            // and `this` is never dropped, so the value is only read once
+            Some(unsafe { (*this.value.get()).assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Default for OnceInit<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceInit<T> {
+    fn drop(&mut self) {
+        if self.state.get() == COMPLETE {
+            // SAFETY: the state is complete, so the value is initialized
+            unsafe { self.value.get_mut().assume_init_drop() }
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for OnceInit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_tuple("OnceInit");
+        match self.get() {
+            Some(value) => f.field(value),
+            None => f.field(&format_args!("<uninit>")),
+        };
+        f.finish()
+    }
+}
+
+/// A thread-safe state machine which runs an initialization function at most once
+struct OnceState(AtomicU8);
+
+const INCOMPLETE: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+impl OnceState {
+    const fn new() -> Self {
+        Self(AtomicU8::new(INCOMPLETE))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.0.load(Ordering::Acquire) == COMPLETE
+    }
+
+    /// Run `f` if the state isn't complete, waiting on any other thread which is running it
+    ///
+    /// If `f` fails or panics, then the state is reset so that another caller may try again
+    fn call_once<E>(&self, f: impl FnOnce() -> Result<(), E>) -> Result<(), E> {
+        struct Reset<'a>(&'a AtomicU8);
+
+        impl Drop for Reset<'_> {
+            fn drop(&mut self) {
+                self.0.store(INCOMPLETE, Ordering::Release);
+            }
+        }
+
+        loop {
+            match self.0.compare_exchange_weak(
+                INCOMPLETE,
+                RUNNING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    let reset = Reset(&self.0);
+                    f()?;
+                    core::mem::forget(reset);
+                    self.0.store(COMPLETE, Ordering::Release);
+                    return Ok(());
+                }
+                Err(COMPLETE) => return Ok(()),
+                Err(_) => {
+                    #[cfg(feature = "std")]
+                    std::thread::yield_now();
+                    #[cfg(not(feature = "std"))]
+                    core::hint::spin_loop();
+                }
+            }
+        }
+    }
+}
+
+/// A thread-safe cell which is initialized in place at most once
+///
+/// This is like [`OnceLock`](https://doc.rust-lang.org/std/sync/struct.OnceLock.html),
+/// but the value is initialized in place by an initializer. If multiple threads try to initialize
+/// the cell at the same time, then exactly one runs it's initializer and the others wait for it.
+/// The initializers of the threads which lose are dropped without running.
+///
+/// Initializing the cell from it's own initializer deadlocks.
+pub struct OnceLockInit<T> {
+    state: OnceState,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: the value is only written once by a single thread (`OnceState`), and then
+// shared by reference, so it must be `Sync` and `Send` (since any thread may initialize it)
+unsafe impl<T: Send + Sync> Sync for OnceLockInit<T> {}
+// SAFETY: the cell owns a `T`
+unsafe impl<T: Send> Send for OnceLockInit<T> {}
+
+impl<T> OnceLockInit<T> {
+    /// Create a new uninitialized cell
+    pub const fn new() -> Self {
+        Self {
+            state: OnceState::new(),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get the value, if it was initialized
+    pub fn get(&self) -> Option<&T> {
+        if self.state.is_complete() {
+            // SAFETY: the state is complete, so the value is initialized
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Get the value mutably, if it was initialized
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.state.is_complete() {
+            // SAFETY: the state is complete, so the value is initialized
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Get the value, or initialize it in place with `init` if the cell is empty
+    ///
+    /// If the cell is already initialized, then `init` is dropped without running
+    pub fn get_or_init_with<I: Initialize<T>>(&self, init: I) -> &T {
+        match self.get_or_try_init_with(init) {
+            Ok(value) => value,
+            Err(err) => match err {},
+        }
+    }
+
+    /// Get the value, or attempt to initialize it in place with `init` if the cell is empty
+    ///
+    /// If initialization fails, then the cell stays empty, and another thread may initialize it.
+    /// If the cell is already initialized, then `init` is dropped without running
+    pub fn get_or_try_init_with<I: TryInitialize<T>>(&self, init: I) -> Result<&T, I::Error> {
+        self.state.call_once(|| {
+            // SAFETY: `OnceState` ensures that only this thread has access to the value
+            let uninit = Uninit::from_maybe_uninit(unsafe { &mut *self.value.get() });
+            // the value is owned by `self`, and is dropped when `self` is dropped
+            uninit.try_init(init)?.leak();
+            Ok(())
+        })?;
+
+        // SAFETY: the value was initialized by `call_once`
+        Ok(unsafe { (*self.value.get()).assume_init_ref() })
+    }
+
+    /// Take the value out of the cell, leaving it empty
+    pub fn take(&mut self) -> Option<T> {
+        core::mem::take(self).into_inner()
+    }
+
+    /// Get the value, if it was initialized
+    pub fn into_inner(self) -> Option<T> {
+        let this = core::mem::ManuallyDrop::new(self);
+        if this.state.is_complete() {
+            // SAFETY: the state is complete, so the value is initialized
+            // and `this` is never dropped, so the value is only read once
+            Some(unsafe { (*this.value.get()).assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Default for OnceLockInit<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceLockInit<T> {
+    fn drop(&mut self) {
+        if self.state.is_complete() {
+            // SAFETY: the state is complete, so the value is initialized
+            unsafe { self.value.get_mut().assume_init_drop() }
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for OnceLockInit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_tuple("OnceLockInit");
+        match self.get() {
+            Some(value) => f.field(value),
+            None => f.field(&format_args!("<uninit>")),
+        };
+        f.finish()
+    }
+}
+
+/// A value which is initialized in place on first access, this is usually created by [`static_emplace`](crate::static_emplace)
+///
+/// If multiple threads access the value at the same time, then exactly one runs the initializer
+/// and the others wait for it. Accessing the value from it's own initializer deadlocks.
+pub struct StaticLazy<T> {
+    cell: OnceLockInit<T>,
+    init: fn(Uninit<'_, T>) -> Init<'_, T>,
+}
+
+impl<T> StaticLazy<T> {
+    /// Create a new lazy value which is initialized by `init` on first access
+    pub const fn new(init: fn(Uninit<'_, T>) -> Init<'_, T>) -> Self {
+        Self {
+            cell: OnceLockInit::new(),
+            init,
+        }
+    }
+
+    /// Get the value, initializing it if this is the first access
+    pub fn get(&self) -> &T {
+        self.cell
+            .get_or_init_with(crate::func::InitFn::new(self.init))
+    }
+
+    /// Get the value, if it was already initialized
+    pub fn get_if_init(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+impl<T> Deref for StaticLazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for StaticLazy<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_tuple("StaticLazy");
+        match self.get_if_init() {
+            Some(value) => f.field(value),
+            None => f.field(&format_args!("<uninit>")),
+        };
+        f.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_static_uninit() {
+        static SLOT: StaticUninit<[u8; 4]> = StaticUninit::new();
+        static FAILED: StaticUninit<[u8; 4]> = StaticUninit::new();
+
+        assert!(!SLOT.is_taken());
+        let value = SLOT.init_with([1; 4]);
+        value[0] = 0;
+        assert_eq!(*value, [0, 1, 1, 1]);
+        assert!(SLOT.is_taken());
+        assert_eq!(
+            SLOT.try_init_with([2; 4]).err(),
+            Some(StaticInitError::AlreadyTaken)
+        );
+
+        let err = FAILED.try_init_with(crate::slice::SliceIterInit::new(0..2));
+        assert!(matches!(err, Err(StaticInitError::Init(_))));
+        assert!(FAILED.take().is_none());
+    }
+
+    #[test]
+    fn test_static_emplace() {
+        crate::static_emplace! {
+            static TABLE: [u32; 8] = crate::array::ArrayFromFn::new(|i| i as u32 * 3);
+        }
+
+        assert!(TABLE.get_if_init().is_none());
+        assert_eq!(TABLE[2], 6);
+        assert_eq!(*TABLE.get_if_init().unwrap(), [0, 3, 6, 9, 12, 15, 18, 21]);
+    }
+
+    #[test]
+    fn test_once_init() {
+        let mut cell = OnceInit::<[u8; 3]>::new();
+        assert!(cell.get().is_none());
+
+        let err = cell.get_or_try_init_with(crate::slice::SliceIterInit::new(0..1));
+        assert!(err.is_err());
+        assert!(cell.get().is_none());
+
+        assert_eq!(*cell.get_or_init_with([1; 3]), [1; 3]);
+        assert_eq!(*cell.get_or_init_with([2; 3]), [1; 3]);
+
+        cell.get_mut().unwrap()[0] = 0;
+        assert_eq!(cell.take(), Some([0, 1, 1]));
+        assert!(cell.get().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_once_lock_init() {
+        static CELL: OnceLockInit<[u32; 64]> = OnceLockInit::new();
+        static RUNS: AtomicU8 = AtomicU8::new(0);
+
+        std::thread::scope(|s| {
+            for t in 0..4 {
+                s.spawn(move || {
+                    let value = CELL.get_or_init_with(crate::func::InitFn::new(|uninit| {
+                        RUNS.fetch_add(1, Ordering::Relaxed);
+                        uninit.write([t; 64])
+                    }));
+                    assert!(value.iter().all(|&x| x == value[0]));
+                });
+            }
+        });
+        assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+
+        let mut cell = OnceLockInit::<u8>::new();
+        assert!(cell
+            .get_or_try_init_with(crate::func::TryInitFn::new(|_| Err(())))
+            .is_err());
+        assert_eq!(*cell.get_or_init_with(3), 3);
+        assert_eq!(cell.take(), Some(3));
+    }
+}
@@ -0,0 +1,195 @@
+//! a uniform way to emplace values into any smart pointer
+//!
+//! [`Emplace`] is implemented for [`Box`], [`Rc`](alloc::rc::Rc), and [`Arc`](alloc::sync::Arc),
+//! and may be implemented for third-party smart pointers, so that [`emplace::<P>`](emplace)
+//! works for all of them.
+
+#[cfg(not(no_global_oom_handling))]
+use core::{alloc::Layout, fmt::Debug, ptr::Pointee};
+
+use ::alloc::boxed::Box;
+
+#[cfg(not(no_global_oom_handling))]
+use crate::traits::HasLayoutProvider;
+use crate::{
+    boxed::AllocError,
+    traits::{LayoutProvider, TryInitialize},
+};
+
+/// A smart pointer which can be created by initializing it's target in place
+///
+/// # Safety
+///
+/// `try_emplace` must only return `Ok` if the target was initialized with `init`,
+/// using the layout given by `provider`
+pub unsafe trait Emplace: Sized {
+    /// The type this smart pointer points to
+    type Target: ?Sized;
+
+    /// Allocate a new target, and attempt to initialize it in place
+    fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+    where
+        I: TryInitialize<Self::Target>,
+        L: LayoutProvider<Self::Target, I>;
+}
+
+/// create a new smart pointer, and initialize it's target in place
+#[cfg(not(no_global_oom_handling))]
+pub fn emplace<P: Emplace, L, I>(provider: L, init: I) -> P
+where
+    I: TryInitialize<P::Target>,
+    L: LayoutProvider<P::Target, I>,
+    I::Error: Debug,
+{
+    match P::try_emplace(provider, init) {
+        Ok(ptr) => ptr,
+        Err(ref err) => crate::boxed::handle(err),
+    }
+}
+
+/// create a new smart pointer, and attempt to initialize it's target in place
+pub fn try_emplace<P: Emplace, L, I>(provider: L, init: I) -> Result<P, AllocError<I::Error>>
+where
+    I: TryInitialize<P::Target>,
+    L: LayoutProvider<P::Target, I>,
+{
+    P::try_emplace(provider, init)
+}
+
+/// create a new smart pointer, and initialize it's target in place, using the default layout provider
+#[cfg(not(no_global_oom_handling))]
+pub fn new<P: Emplace, I>(init: I) -> P
+where
+    P::Target: HasLayoutProvider,
+    I: TryInitialize<P::Target>,
+    <P::Target as HasLayoutProvider>::LayoutProvider: LayoutProvider<P::Target, I>,
+    I::Error: Debug,
+{
+    emplace(P::Target::layout_provider(), init)
+}
+
+/// Get the metadata of the target, and check that the provider's layout matches it
+#[cfg(not(no_global_oom_handling))]
+fn checked_metadata<T: ?Sized, L, I, E>(
+    provider: &L,
+    init: &I,
+) -> Result<<T as Pointee>::Metadata, AllocError<E>>
+where
+    L: LayoutProvider<T, I>,
+{
+    let provided = provider.layout_for(init).map_err(AllocError::Layout)?;
+    let ptr = provider.cast(init, provided.align() as *mut u8);
+
+    // SAFETY: the pointer came from the layout provider, so it has valid metadata for T
+    // which fits in the layout that was computed
+    let required = unsafe { Layout::for_value_raw(ptr) };
+    if required != provided {
+        return Err(AllocError::LayoutMismatch { provided, required });
+    }
+
+    Ok(core::ptr::metadata(ptr))
+}
+
+// SAFETY: `boxed::try_emplace` initializes the value in place with the provider's layout
+unsafe impl<T: ?Sized> Emplace for Box<T> {
+    type Target = T;
+
+    fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+    where
+        I: TryInitialize<T>,
+        L: LayoutProvider<T, I>,
+    {
+        crate::boxed::try_emplace(provider, init)
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+macro_rules! shared {
+    ($module:ident, $ptr:ident) => {
+        // SAFETY: the provider's layout is checked to be the layout of `T`,
+        // and the value is initialized in place
+        unsafe impl<T> Emplace for $ptr<T> {
+            type Target = T;
+
+            fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+            where
+                I: TryInitialize<T>,
+                L: LayoutProvider<T, I>,
+            {
+                checked_metadata(&provider, &init)?;
+                crate::$module::try_emplace(init).map_err(AllocError::Init)
+            }
+        }
+
+        // SAFETY: the length is taken from the provider, and the
+        // provider's layout is checked to be the layout of `[T]`
+        unsafe impl<T> Emplace for $ptr<[T]> {
+            type Target = [T];
+
+            fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+            where
+                I: TryInitialize<[T]>,
+                L: LayoutProvider<[T], I>,
+            {
+                let len = checked_metadata(&provider, &init)?;
+                crate::$module::try_emplace_slice(len, init).map_err(AllocError::Init)
+            }
+        }
+
+        // SAFETY: the length is taken from the provider, and the
+        // provider's layout is checked to be the layout of `str`
+        unsafe impl Emplace for $ptr<str> {
+            type Target = str;
+
+            fn try_emplace<L, I>(provider: L, init: I) -> Result<Self, AllocError<I::Error>>
+            where
+                I: TryInitialize<str>,
+                L: LayoutProvider<str, I>,
+            {
+                let len = checked_metadata(&provider, &init)?;
+                crate::$module::try_emplace_str(len, init).map_err(AllocError::Init)
+            }
+        }
+    };
+}
+
+#[cfg(not(no_global_oom_handling))]
+use ::alloc::rc::Rc;
+#[cfg(all(not(no_global_oom_handling), target_has_atomic = "ptr"))]
+use ::alloc::sync::Arc;
+
+#[cfg(not(no_global_oom_handling))]
+shared!(rc, Rc);
+#[cfg(all(not(no_global_oom_handling), target_has_atomic = "ptr"))]
+shared!(arc, Arc);
+
+#[cfg(all(test, not(no_global_oom_handling)))]
+mod test {
+    use super::*;
+    use crate::layout::{SizedLayoutProvider, SliceLayoutProvider, StrLayoutProvider};
+
+    fn make<P: Emplace<Target = [u32]> + core::ops::Deref<Target = [u32]>>() -> P {
+        emplace(
+            SliceLayoutProvider(3),
+            crate::slice::SliceIterInit::new(0..),
+        )
+    }
+
+    #[test]
+    fn test_emplace() {
+        assert_eq!(*make::<Box<[u32]>>(), [0, 1, 2]);
+        assert_eq!(*make::<Rc<[u32]>>(), [0, 1, 2]);
+        assert_eq!(*make::<Arc<[u32]>>(), [0, 1, 2]);
+
+        let rc: Rc<u8> = new(3);
+        assert_eq!(*rc, 3);
+        let arc: Arc<str> = emplace(StrLayoutProvider(2), crate::str::StrInit::new("hi"));
+        assert_eq!(&*arc, "hi");
+
+        let err = try_emplace::<Rc<u8>, _, _>(
+            SizedLayoutProvider,
+            crate::func::TryInitFn::new(|_| Err(())),
+        );
+        assert!(matches!(err, Err(AllocError::Init(()))));
+    }
+}
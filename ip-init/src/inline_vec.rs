@@ -0,0 +1,301 @@
+//! a fixed-capacity vector stored inline, which initializes it's elements in place
+
+use core::{
+    fmt::Debug,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{slice::SliceWriter, traits::TryInitialize, Uninit};
+
+/// A vector with room for `N` elements stored inline, which doesn't need an allocator
+///
+/// Elements are initialized in place in the spare capacity, either one at a time with
+/// [`InlineVec::emplace_back`], or in bulk with [`InlineVec::extend_init`] and [`InlineVec::extend_with`]
+pub struct InlineVec<T, const N: usize> {
+    len: usize,
+    items: [MaybeUninit<T>; N],
+}
+
+/// The error for when elements can't be added to an [`InlineVec`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineVecError<E> {
+    /// There isn't enough spare capacity for the new elements
+    Full,
+    /// Initialization failed, the vector is unchanged
+    Init(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for InlineVecError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Full => f.write_str("not enough spare capacity in the vector"),
+            Self::Init(err) => write!(f, "failed to initialize value: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for InlineVecError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Full => None,
+            Self::Init(err) => Some(err),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn handle<E: Debug>(err: InlineVecError<E>) -> ! {
+    match err {
+        InlineVecError::Full => panic!("Tried to add elements to a full `InlineVec`"),
+        InlineVecError::Init(err) => crate::init_failed(err),
+    }
+}
+
+impl<T, const N: usize> InlineVec<T, N> {
+    /// Create a new empty vector
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            items: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    /// The number of elements the vector can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of elements which can still be added to the vector
+    pub fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns `true` if the vector has no spare capacity
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Get the uninitialized spare capacity
+    pub fn spare_capacity_mut(&mut self) -> Uninit<'_, [T]> {
+        Uninit::from_maybe_uninit_slice(&mut self.items[self.len..])
+    }
+
+    /// Append an element to the back of the vector, and initialize it in place
+    ///
+    /// # Panics
+    ///
+    /// If the vector is full, or initialization fails
+    pub fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_back(init) {
+            Ok(value) => value,
+            Err(err) => handle(err),
+        }
+    }
+
+    /// Append an element to the back of the vector, and attempt to initialize it in place
+    pub fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, InlineVecError<I::Error>>
+    where
+        I: TryInitialize<T>,
+    {
+        let slot = self.items.get_mut(self.len).ok_or(InlineVecError::Full)?;
+        let ptr = Uninit::from_maybe_uninit(slot)
+            .try_init(init)
+            .map_err(InlineVecError::Init)?
+            .into_raw();
+        // the ownership of the element was passed to the vector by `into_raw`
+        self.len += 1;
+
+        // SAFETY: the element was just initialized, and is borrowed from self
+        Ok(unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Append `len` elements to the back of the vector, and attempt to initialize them in place
+    pub fn extend_init<I>(
+        &mut self,
+        len: usize,
+        init: I,
+    ) -> Result<&mut [T], InlineVecError<I::Error>>
+    where
+        I: TryInitialize<[T]>,
+    {
+        if len > self.remaining_capacity() {
+            return Err(InlineVecError::Full);
+        }
+
+        let start = self.len;
+        let slots = &mut self.items[start..start + len];
+        let ptr = Uninit::from_maybe_uninit_slice(slots)
+            .try_init(init)
+            .map_err(InlineVecError::Init)?
+            .into_raw();
+        // the ownership of the elements was passed to the vector by `into_raw`
+        self.len += len;
+
+        // SAFETY: the elements were just initialized, and are borrowed from self
+        Ok(unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Append elements to the back of the vector by writing them to the spare capacity with a [`SliceWriter`]
+    ///
+    /// All elements which were written when `f` returns are added to the vector.
+    /// If `f` panics, then the written elements are dropped
+    ///
+    /// # Panics
+    ///
+    /// If `f` replaces the writer with one which doesn't write to the spare capacity
+    pub fn extend_with<R>(&mut self, f: impl FnOnce(&mut SliceWriter<'_, T>) -> R) -> R {
+        let spare = self.items[self.len..].as_ptr().cast::<T>();
+        let remaining = self.remaining_capacity();
+
+        let mut writer = SliceWriter::new(self.spare_capacity_mut());
+        let output = f(&mut writer);
+        let (init, _) = writer.finish_partial();
+        let written = init.len();
+
+        // `f` could swap in a writer to some other memory, and then it's elements
+        // must not be added to the vector
+        assert!(
+            core::ptr::eq(init.as_ptr().cast::<T>(), spare) && written <= remaining,
+            "the writer passed to `extend_with` was replaced"
+        );
+        // the ownership of the elements is passed to the vector
+        init.into_raw();
+        self.len += written;
+        output
+    }
+
+    /// Remove the last element from the vector
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // SAFETY: the element was initialized, and isn't part of the vector anymore
+        Some(unsafe { self.items[self.len].assume_init_read() })
+    }
+
+    /// Shorten the vector to `len` elements, and drop the rest
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail = core::ptr::slice_from_raw_parts_mut(
+            self.items[len..self.len].as_mut_ptr().cast::<T>(),
+            self.len - len,
+        );
+        // the length is set first, so that if dropping panics the elements are leaked
+        self.len = len;
+        // SAFETY: the elements were initialized, and aren't part of the vector anymore
+        unsafe { tail.drop_in_place() }
+    }
+
+    /// Remove all elements from the vector
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// View the elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { &*(&self.items[..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// View the elements as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { &mut *(&mut self.items[..self.len] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<T, const N: usize> Default for InlineVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for InlineVec<T, N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T, const N: usize> Deref for InlineVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for InlineVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for InlineVec<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_inline_vec() {
+        let mut vec = InlineVec::<u32, 6>::new();
+        *vec.emplace_back(1) += 1;
+        assert_eq!(*vec, [2]);
+
+        let tail = vec
+            .extend_init(2, crate::slice::SliceIterInit::new(5..))
+            .unwrap();
+        assert_eq!(*tail, [5, 6]);
+
+        let written = vec.extend_with(|writer| {
+            writer.write(8);
+            writer.write(9);
+            writer.initialized()
+        });
+        assert_eq!(written, 2);
+        assert_eq!(*vec, [2, 5, 6, 8, 9]);
+
+        assert!(matches!(
+            vec.extend_init(2, crate::slice::RepeatInit::new(0)),
+            Err(InlineVecError::Full)
+        ));
+        assert!(matches!(
+            vec.try_emplace_back(crate::func::TryInitFn::new(|_| Err(()))),
+            Err(InlineVecError::Init(()))
+        ));
+        vec.emplace_back(10);
+        assert!(vec.is_full());
+        assert!(matches!(vec.try_emplace_back(0), Err(InlineVecError::Full)));
+
+        assert_eq!(vec.pop(), Some(10));
+        vec.truncate(1);
+        assert_eq!(*vec, [2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic = "the writer passed to `extend_with` was replaced"]
+    fn test_extend_with_swapped_writer() {
+        let mut vec = InlineVec::<u32, 4>::new();
+        vec.extend_with(|writer| {
+            let other =
+                ::alloc::boxed::Box::leak(::alloc::boxed::Box::new([MaybeUninit::uninit(); 2]));
+            let mut other = SliceWriter::new(Uninit::from_maybe_uninit_slice(other));
+            other.write(1);
+            other.write(2);
+            core::mem::swap(writer, &mut other);
+        });
+    }
+}
@@ -1,9 +1,19 @@
 //! slice iterators for [`Uninit`], [`PinnedUninit`], and [`Init`]
 
-use core::{marker::PhantomData, mem, pin::Pin, ptr::NonNull};
-
-use crate::{pin_ptr::PinnedUninit, Init, Uninit};
-
+use core::{
+    iter::{FusedIterator, TrustedLen},
+    marker::PhantomData,
+    mem,
+    num::NonZeroUsize,
+    pin::Pin,
+    ptr::NonNull,
+};
+
+use crate::{pin_ptr::PinnedUninit, slice::SliceWriter, Init, Uninit};
+
+/// `start..end` is always the part of the slice which hasn't been yielded from either end
+///
+/// For zero-sized `T`, `end` is the number of remaining elements instead
 struct RawIter<T> {
     start: NonNull<T>,
     end: *const T,
@@ -41,6 +51,9 @@ impl<T> RawIter<T> {
 }
 
 impl<T> ExactSizeIterator for RawIter<T> {}
+impl<T> FusedIterator for RawIter<T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<T> TrustedLen for RawIter<T> {}
 impl<T> Iterator for RawIter<T> {
     type Item = NonNull<T>;
 
@@ -65,25 +78,23 @@ impl<T> Iterator for RawIter<T> {
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+
         if Self::ZERO_SIZED {
-            let end = self.end as usize;
-            let end = end.checked_sub(n)?;
-            self.end = core::ptr::invalid_mut(end);
-            self.next()
-        } else if self.len() >= n {
-            self.end = self.start.as_ptr();
-            None
+            self.end = core::ptr::invalid_mut(self.len() - step);
         } else {
-            // SAFETY: the pointer still hasn't reached the end, so adding one is safe
-            let next = unsafe { self.start.as_ptr().add(n) };
+            // SAFETY: step <= len, so the pointer stays in bounds of the slice
+            let next = unsafe { self.start.as_ptr().add(step) };
             // SAFETY: the pointer is still in the same allocation, so must be non-null
-            let ptr = unsafe { NonNull::new_unchecked(next) };
-
-            // SAFETY: the pointer is still in the same allocation, so must be non-null
-            self.start = unsafe { NonNull::new_unchecked(next.add(1)) };
-
-            Some(ptr)
+            self.start = unsafe { NonNull::new_unchecked(next) };
         }
+
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -122,18 +133,20 @@ impl<T> DoubleEndedIterator for RawIter<T> {
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        if Self::ZERO_SIZED {
-            self.nth(n)
-        } else if self.len() >= n {
-            self.end = self.start.as_ptr();
-            None
-        } else {
-            // SAFETY: The end is still in the allocation because it wasn't equal to start
-            unsafe { self.end = self.end.sub(n + 1) }
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
 
-            // SAFETY: the end pointer is always non-null for non-zero sized T
-            Some(unsafe { NonNull::new_unchecked(self.end as _) })
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        if Self::ZERO_SIZED {
+            return self.advance_by(n);
         }
+
+        let step = n.min(self.len());
+        // SAFETY: step <= len, so the pointer stays in bounds of the slice
+        unsafe { self.end = self.end.sub(step) }
+
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
     }
 }
 
@@ -154,6 +167,9 @@ impl<'a, T> UninitIter<'a, T> {
     }
 
     /// Get the rest of the slice
+    ///
+    /// This is the part of the slice which hasn't been yielded from either end,
+    /// so it is correct to mix `next` and `next_back` before calling `finish`
     pub fn finish(self) -> Uninit<'a, [T]> {
         let ptr = self.raw.as_slice();
         // SAFETY: the slice came from an Uninit so is allocated
@@ -161,9 +177,43 @@ impl<'a, T> UninitIter<'a, T> {
         // the iterator
         unsafe { Uninit::from_raw_nonnull(ptr) }
     }
+
+    /// Split the remaining slots of this iterator into two iterators over disjoint halves
+    ///
+    /// The first will contain the next `mid` slots, and the second will contain the rest
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        let mut rest = self.finish();
+        let len = rest.len();
+        assert!(mid <= len);
+        let (head, tail) = rest.split_at_mut(mid);
+        let (head, tail) = (head.as_non_null_ptr(), tail.as_non_null_ptr());
+        // SAFETY: the two halves are disjoint sub-slices of `rest`, which lives for `'a`
+        unsafe {
+            (
+                Self::new(Uninit::from_raw_nonnull(head)),
+                Self::new(Uninit::from_raw_nonnull(tail)),
+            )
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Uninit<'a, [T]> {
+    type Item = Uninit<'a, T>;
+    type IntoIter = UninitIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UninitIter::new(self)
+    }
 }
 
 impl<'a, T> ExactSizeIterator for UninitIter<'a, T> {}
+impl<'a, T> FusedIterator for UninitIter<'a, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<'a, T> TrustedLen for UninitIter<'a, T> {}
 impl<'a, T> Iterator for UninitIter<'a, T> {
     type Item = Uninit<'a, T>;
 
@@ -179,6 +229,10 @@ impl<'a, T> Iterator for UninitIter<'a, T> {
         ptr.map(|ptr| unsafe { Uninit::from_raw_nonnull(ptr) })
     }
 
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_by(n)
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.raw.size_hint()
     }
@@ -195,6 +249,10 @@ impl<'a, T> DoubleEndedIterator for UninitIter<'a, T> {
         // SAFETY: this pointer is derived from an uninit pointer
         ptr.map(|ptr| unsafe { Uninit::from_raw_nonnull(ptr) })
     }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_back_by(n)
+    }
 }
 
 /// An iterator over [`Init<_>`] pointers
@@ -224,6 +282,9 @@ impl<'a, T> InitIter<'a, T> {
     }
 
     /// Get the rest of the slice
+    ///
+    /// This is the part of the slice which hasn't been yielded from either end,
+    /// so it is correct to mix `next` and `next_back` before calling `finish`
     pub fn finish(self) -> Init<'a, [T]> {
         let ptr = self.raw.as_slice();
         core::mem::forget(self);
@@ -232,9 +293,37 @@ impl<'a, T> InitIter<'a, T> {
         // the iterator
         unsafe { Init::from_raw_nonnull(ptr) }
     }
+
+    /// Views the remaining elements of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the slice came from an Init so is initialized
+        // and the slice doesn't alias with any pointer given out by
+        // the iterator
+        unsafe { self.raw.as_slice().as_ref() }
+    }
+
+    /// Views the remaining elements of this iterator as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the slice came from an Init so is initialized
+        // and the slice doesn't alias with any pointer given out by
+        // the iterator
+        unsafe { self.raw.as_slice().as_mut() }
+    }
+}
+
+impl<'a, T> IntoIterator for Init<'a, [T]> {
+    type Item = Init<'a, T>;
+    type IntoIter = InitIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        InitIter::new(self)
+    }
 }
 
 impl<'a, T> ExactSizeIterator for InitIter<'a, T> {}
+impl<'a, T> FusedIterator for InitIter<'a, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<'a, T> TrustedLen for InitIter<'a, T> {}
 impl<'a, T> Iterator for InitIter<'a, T> {
     type Item = Init<'a, T>;
 
@@ -245,9 +334,19 @@ impl<'a, T> Iterator for InitIter<'a, T> {
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let ptr = self.raw.nth(n);
-        // SAFETY: this pointer is derived from an Init pointer
-        ptr.map(|ptr| unsafe { Init::from_raw_nonnull(ptr) })
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        let ptr = self.raw.as_slice().as_ptr().cast::<T>();
+        let skipped = core::ptr::slice_from_raw_parts_mut(ptr, step);
+        let _ = self.raw.advance_by(step);
+        // SAFETY: the skipped elements came from an Init so are initialized
+        // and are no longer tracked by the iterator
+        unsafe { skipped.drop_in_place() }
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -262,9 +361,401 @@ impl<'a, T> DoubleEndedIterator for InitIter<'a, T> {
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let ptr = self.raw.nth_back(n);
-        // SAFETY: this pointer is derived from an Init pointer
-        ptr.map(|ptr| unsafe { Init::from_raw_nonnull(ptr) })
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = n.min(self.len());
+        let rest = self.raw.as_slice();
+        // SAFETY: step <= len, so the pointer stays in bounds of the slice
+        let ptr = unsafe { rest.as_ptr().cast::<T>().add(rest.len() - step) };
+        let skipped = core::ptr::slice_from_raw_parts_mut(ptr, step);
+        let _ = self.raw.advance_back_by(step);
+        // SAFETY: the skipped elements came from an Init so are initialized
+        // and are no longer tracked by the iterator
+        unsafe { skipped.drop_in_place() }
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+}
+
+/// A by-value iterator over the elements of an [`Init<[T]>`](Init)
+///
+/// This is the in-place equivalent of `vec::IntoIter`, any elements which aren't yielded
+/// are dropped with the iterator
+pub struct IntoValues<'a, T> {
+    raw: InitIter<'a, T>,
+}
+
+impl<'a, T> IntoValues<'a, T> {
+    /// Create a new by-value iterator over the elements of the slice
+    pub fn new(init: Init<'a, [T]>) -> Self {
+        Self {
+            raw: InitIter::new(init),
+        }
+    }
+
+    /// Get the rest of the slice
+    ///
+    /// This is the part of the slice which hasn't been yielded from either end,
+    /// so it is correct to mix `next` and `next_back` before calling `finish`
+    pub fn finish(self) -> Init<'a, [T]> {
+        self.raw.finish()
+    }
+
+    /// Views the remaining elements of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.raw.as_slice()
+    }
+
+    /// Views the remaining elements of this iterator as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.raw.as_mut_slice()
+    }
+}
+
+impl<'a, T> Init<'a, [T]> {
+    /// Create a by-value iterator over the elements of the slice
+    pub fn into_values(self) -> IntoValues<'a, T> {
+        IntoValues::new(self)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoValues<'_, T> {}
+impl<T> FusedIterator for IntoValues<'_, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<T> TrustedLen for IntoValues<'_, T> {}
+impl<T> Iterator for IntoValues<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.raw.next().map(Init::into_inner)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.raw.nth(n).map(Init::into_inner)
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_by(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for IntoValues<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.raw.next_back().map(Init::into_inner)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.raw.nth_back(n).map(Init::into_inner)
+    }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_back_by(n)
+    }
+}
+
+/// Split a slice into the pointer to the `[T; N]` chunks and the pointer to the remainder
+///
+/// # Panics
+///
+/// if `N == 0`
+fn split_chunks<T, const N: usize>(ptr: NonNull<[T]>) -> (*mut [[T; N]], *mut [T]) {
+    assert!(N != 0, "chunk size must be non-zero");
+    let len = ptr.len();
+    let chunks = len / N;
+    let ptr = ptr.as_ptr().cast::<T>();
+    // SAFETY: `chunks * N <= len`, so the pointer stays in bounds of the slice
+    let rem = unsafe { ptr.add(chunks * N) };
+    (
+        core::ptr::slice_from_raw_parts_mut(ptr.cast(), chunks),
+        core::ptr::slice_from_raw_parts_mut(rem, len % N),
+    )
+}
+
+/// An iterator over [`Uninit<[T; N]>`](Uninit) chunks of a slice
+///
+/// If the length of the slice isn't a multiple of `N`, then the last up to
+/// `N - 1` elements are available from [`UninitChunksExact::into_remainder`]
+pub struct UninitChunksExact<'a, T, const N: usize> {
+    chunks: UninitIter<'a, [T; N]>,
+    remainder: Uninit<'a, [T]>,
+}
+
+impl<'a, T, const N: usize> UninitChunksExact<'a, T, N> {
+    /// Create a new iterator over uninit chunks
+    ///
+    /// # Panics
+    ///
+    /// if `N == 0`
+    pub fn new(uninit: Uninit<'a, [T]>) -> Self {
+        let (chunks, remainder) = split_chunks::<T, N>(uninit.as_non_null_ptr());
+        // SAFETY: the chunks and remainder are disjoint sub-slices of `uninit`
+        unsafe {
+            Self {
+                chunks: UninitIter::new(Uninit::from_raw(chunks)),
+                remainder: Uninit::from_raw(remainder),
+            }
+        }
+    }
+
+    /// The length of the remainder which isn't covered by any chunk
+    pub fn remainder_len(&self) -> usize {
+        self.remainder.len()
+    }
+
+    /// Get the remainder which isn't covered by any chunk
+    pub fn into_remainder(self) -> Uninit<'a, [T]> {
+        self.remainder
+    }
+}
+
+impl<'a, T> Uninit<'a, [T]> {
+    /// Create an iterator over `N` element chunks of this slice
+    ///
+    /// See [`UninitChunksExact`] for details
+    ///
+    /// # Panics
+    ///
+    /// if `N == 0`
+    pub fn chunks_exact<const N: usize>(self) -> UninitChunksExact<'a, T, N> {
+        UninitChunksExact::new(self)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for UninitChunksExact<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for UninitChunksExact<'_, T, N> {}
+// SAFETY: the size hint is always exact
+unsafe impl<T, const N: usize> TrustedLen for UninitChunksExact<'_, T, N> {}
+impl<'a, T, const N: usize> Iterator for UninitChunksExact<'a, T, N> {
+    type Item = Uninit<'a, [T; N]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n)
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.chunks.advance_by(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+impl<T, const N: usize> DoubleEndedIterator for UninitChunksExact<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth_back(n)
+    }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.chunks.advance_back_by(n)
+    }
+}
+
+/// An iterator over [`Init<[T; N]>`](Init) chunks of a slice
+///
+/// If the length of the slice isn't a multiple of `N`, then the last up to
+/// `N - 1` elements are available from [`InitChunksExact::remainder`].
+/// Any chunks which aren't yielded and the remainder are dropped with the iterator
+pub struct InitChunksExact<'a, T, const N: usize> {
+    chunks: InitIter<'a, [T; N]>,
+    remainder: Init<'a, [T]>,
+}
+
+impl<'a, T, const N: usize> InitChunksExact<'a, T, N> {
+    /// Create a new iterator over init chunks
+    ///
+    /// # Panics
+    ///
+    /// if `N == 0`
+    pub fn new(init: Init<'a, [T]>) -> Self {
+        let (chunks, remainder) = split_chunks::<T, N>(init.into_raw());
+        // SAFETY: the chunks and remainder are disjoint sub-slices of `init`
+        unsafe {
+            Self {
+                chunks: InitIter::new(Init::from_raw(chunks)),
+                remainder: Init::from_raw(remainder),
+            }
+        }
+    }
+
+    /// The remainder which isn't covered by any chunk
+    pub fn remainder(&self) -> &[T] {
+        &self.remainder
+    }
+
+    /// Get the remainder which isn't covered by any chunk
+    ///
+    /// This drops any chunks which weren't yielded
+    pub fn into_remainder(self) -> Init<'a, [T]> {
+        self.remainder
+    }
+}
+
+impl<'a, T> Init<'a, [T]> {
+    /// Create an iterator over `N` element chunks of this slice
+    ///
+    /// See [`InitChunksExact`] for details
+    ///
+    /// # Panics
+    ///
+    /// if `N == 0`
+    pub fn chunks_exact<const N: usize>(self) -> InitChunksExact<'a, T, N> {
+        InitChunksExact::new(self)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for InitChunksExact<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for InitChunksExact<'_, T, N> {}
+// SAFETY: the size hint is always exact
+unsafe impl<T, const N: usize> TrustedLen for InitChunksExact<'_, T, N> {}
+impl<'a, T, const N: usize> Iterator for InitChunksExact<'a, T, N> {
+    type Item = Init<'a, [T; N]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth(n)
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.chunks.advance_by(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+impl<T, const N: usize> DoubleEndedIterator for InitChunksExact<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.chunks.nth_back(n)
+    }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.chunks.advance_back_by(n)
+    }
+}
+
+/// Pair each slot of `uninit` with an item from `data`, see [`ZipInit`] for details
+pub fn zip_init<'a, T, I: IntoIterator>(
+    uninit: Uninit<'a, [T]>,
+    data: I,
+) -> ZipInit<'a, T, I::IntoIter> {
+    ZipInit {
+        writer: SliceWriter::new(uninit),
+        data: data.into_iter(),
+    }
+}
+
+/// The initialized prefix and the uninitialized tail of a slice
+type Parts<'a, T> = (Init<'a, [T]>, Uninit<'a, [T]>);
+
+/// Initializes each slot of a slice from a corresponding item of an iterator
+///
+/// This isn't an [`Iterator`] because each slot must be initialized before the next
+/// one is handed out, so that the initialized prefix can be tracked. Instead, each
+/// pair is passed to a function which must initialize the slot.
+///
+/// Initialization stops once either the slice or the data is exhausted, and
+/// [`ZipInit::finish`] returns the initialized prefix and the uninitialized tail.
+pub struct ZipInit<'a, T, I> {
+    writer: SliceWriter<'a, T>,
+    data: I,
+}
+
+impl<'a, T, I: Iterator> ZipInit<'a, T, I> {
+    /// Initialize the next slot from the next item of the data
+    ///
+    /// Returns `false` without calling `f` if either the slots or the data are exhausted
+    pub fn write_next(&mut self, f: impl FnOnce(Uninit<'_, T>, I::Item) -> Init<'_, T>) -> bool {
+        match self
+            .try_write_next(|uninit, item| Ok::<_, core::convert::Infallible>(f(uninit, item)))
+        {
+            Some(Ok(())) => true,
+            None => false,
+        }
+    }
+
+    /// Try to initialize the next slot from the next item of the data
+    ///
+    /// Returns `None` without calling `f` if either the slots or the data are exhausted.
+    /// If `f` fails, the slot remains uninitialized
+    pub fn try_write_next<E>(
+        &mut self,
+        f: impl FnOnce(Uninit<'_, T>, I::Item) -> Result<Init<'_, T>, E>,
+    ) -> Option<Result<(), E>> {
+        if self.writer.is_finished() {
+            return None;
+        }
+
+        let item = self.data.next()?;
+        Some(
+            self.writer
+                .try_write(crate::func::TryInitFn::new(|uninit| f(uninit, item))),
+        )
+    }
+
+    /// Initialize slots until either the slots or the data are exhausted
+    /// and return the initialized prefix and the uninitialized tail
+    pub fn for_each(
+        mut self,
+        mut f: impl FnMut(Uninit<'_, T>, I::Item) -> Init<'_, T>,
+    ) -> Parts<'a, T> {
+        while self.write_next(&mut f) {}
+        self.finish()
+    }
+
+    /// Try to initialize slots until either the slots or the data are exhausted
+    /// and return the initialized prefix and the uninitialized tail
+    ///
+    /// If `f` fails, the error is returned and the initialized prefix is dropped
+    pub fn try_for_each<E>(
+        mut self,
+        mut f: impl FnMut(Uninit<'_, T>, I::Item) -> Result<Init<'_, T>, E>,
+    ) -> Result<Parts<'a, T>, E> {
+        while let Some(result) = self.try_write_next(&mut f) {
+            result?
+        }
+        Ok(self.finish())
+    }
+
+    /// The number of slots which were initialized
+    pub fn initialized(&self) -> usize {
+        self.writer.initialized()
+    }
+
+    /// The number of slots which are still uninitialized
+    pub fn remaining(&self) -> usize {
+        self.writer.remaining()
+    }
+
+    /// Get the initialized prefix and the uninitialized tail of the slice
+    pub fn finish(self) -> Parts<'a, T> {
+        self.writer.finish_partial()
+    }
+
+    /// Get the initialized prefix and the uninitialized tail of the slice,
+    /// and the rest of the data
+    pub fn finish_with_data(self) -> (Init<'a, [T]>, Uninit<'a, [T]>, I) {
+        let (init, uninit) = self.writer.finish_partial();
+        (init, uninit, self.data)
     }
 }
 
@@ -283,13 +774,41 @@ impl<'a, T> PinnedUninitIter<'a, T> {
     }
 
     /// Get the rest of the slice
+    ///
+    /// This is the part of the slice which hasn't been yielded from either end,
+    /// so it is correct to mix `next` and `next_back` before calling `finish`
     pub fn finish(self) -> PinnedUninit<'a, [T]> {
         // SAFETY: the slice came from a `PinnedUninit` so it is in the pinned state
         unsafe { PinnedUninit::new_unchecked(self.raw.finish()) }
     }
+
+    /// Split the remaining slots of this iterator into two iterators over disjoint halves
+    ///
+    /// See [`UninitIter::split_at`] for details
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        let (head, tail) = self.raw.split_at(mid);
+        // both halves came from a `PinnedUninit`, so they are in the pinned state
+        (Self { raw: head }, Self { raw: tail })
+    }
+}
+
+impl<'a, T> IntoIterator for PinnedUninit<'a, [T]> {
+    type Item = PinnedUninit<'a, T>;
+    type IntoIter = PinnedUninitIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PinnedUninitIter::new(self)
+    }
 }
 
 impl<'a, T> ExactSizeIterator for PinnedUninitIter<'a, T> {}
+impl<'a, T> FusedIterator for PinnedUninitIter<'a, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<'a, T> TrustedLen for PinnedUninitIter<'a, T> {}
 impl<'a, T> Iterator for PinnedUninitIter<'a, T> {
     type Item = PinnedUninit<'a, T>;
 
@@ -305,6 +824,10 @@ impl<'a, T> Iterator for PinnedUninitIter<'a, T> {
         ptr.map(|ptr| unsafe { PinnedUninit::new_unchecked(ptr) })
     }
 
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_by(n)
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.raw.size_hint()
     }
@@ -321,6 +844,10 @@ impl<'a, T> DoubleEndedIterator for PinnedUninitIter<'a, T> {
         // SAFETY: this pointer is derived from an uninit pointer
         ptr.map(|ptr| unsafe { PinnedUninit::new_unchecked(ptr) })
     }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_back_by(n)
+    }
 }
 
 /// An iterator over pin [`Pin<Init<_>>`] pointers
@@ -338,6 +865,9 @@ impl<'a, T> PinnedInitIter<'a, T> {
     }
 
     /// Get the rest of the slice
+    ///
+    /// This is the part of the slice which hasn't been yielded from either end,
+    /// so it is correct to mix `next` and `next_back` before calling `finish`
     pub fn finish(self) -> Pin<Init<'a, [T]>> {
         // SAFETY: the slice came from a `Pin` so it is in the pinned state
         unsafe { Pin::new_unchecked(self.raw.finish()) }
@@ -345,6 +875,9 @@ impl<'a, T> PinnedInitIter<'a, T> {
 }
 
 impl<'a, T> ExactSizeIterator for PinnedInitIter<'a, T> {}
+impl<'a, T> FusedIterator for PinnedInitIter<'a, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<'a, T> TrustedLen for PinnedInitIter<'a, T> {}
 impl<'a, T> Iterator for PinnedInitIter<'a, T> {
     type Item = Pin<Init<'a, T>>;
 
@@ -360,6 +893,10 @@ impl<'a, T> Iterator for PinnedInitIter<'a, T> {
         ptr.map(|ptr| unsafe { Pin::new_unchecked(ptr) })
     }
 
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_by(n)
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.raw.size_hint()
     }
@@ -376,6 +913,78 @@ impl<'a, T> DoubleEndedIterator for PinnedInitIter<'a, T> {
         // SAFETY: this pointer is derived from an pinned init pointer
         ptr.map(|ptr| unsafe { Pin::new_unchecked(ptr) })
     }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.raw.advance_back_by(n)
+    }
+}
+
+/// An iterator over [`Pin<&mut T>`] references to the elements of a pinned slice
+///
+/// This is structural pinning for slices, each element of a pinned slice is pinned.
+/// A `Pin<Init<[T]>>` can be iterated with `PinIterMut::new(init.as_mut())`
+pub struct PinIterMut<'a, T> {
+    iter: core::slice::IterMut<'a, T>,
+}
+
+impl<'a, T> PinIterMut<'a, T> {
+    /// Create a new iterator over the elements of a pinned slice
+    pub fn new(slice: Pin<&'a mut [T]>) -> Self {
+        Self {
+            // SAFETY: the elements are only handed out as `Pin<&mut T>`, so they stay pinned
+            iter: unsafe { Pin::into_inner_unchecked(slice) }.iter_mut(),
+        }
+    }
+
+    /// Views the remaining elements of this iterator as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+}
+
+impl<T> ExactSizeIterator for PinIterMut<'_, T> {}
+impl<T> FusedIterator for PinIterMut<'_, T> {}
+// SAFETY: the size hint is always exact
+unsafe impl<T> TrustedLen for PinIterMut<'_, T> {}
+impl<'a, T> Iterator for PinIterMut<'a, T> {
+    type Item = Pin<&'a mut T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        // SAFETY: the element came from a pinned slice, so it is pinned
+        item.map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n);
+        // SAFETY: the element came from a pinned slice, so it is pinned
+        item.map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for PinIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back();
+        // SAFETY: the element came from a pinned slice, so it is pinned
+        item.map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth_back(n);
+        // SAFETY: the element came from a pinned slice, so it is pinned
+        item.map(|item| unsafe { Pin::new_unchecked(item) })
+    }
+
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_back_by(n)
+    }
 }
 
 #[cfg(test)]
@@ -443,6 +1052,164 @@ mod test {
         assert_eq!(count, (array.len() + 2) / 3);
     }
 
+    #[test]
+    fn test_into_iter() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 4];
+        let mut inits = [const { None }; 4];
+        for (i, uninit) in Uninit::from_maybe_uninit_slice(&mut array)
+            .into_iter()
+            .enumerate()
+        {
+            inits[i] = Some(uninit.write(i));
+        }
+        for (i, init) in inits.into_iter().enumerate() {
+            assert_eq!(*init.unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn test_init_as_slice() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 4];
+        let init = Uninit::from_maybe_uninit_slice(&mut array).write_array([0, 1, 2, 3]);
+        let mut iter = init.into_iter();
+        assert_eq!(*iter.next().unwrap(), 0);
+        assert_eq!(*iter.next_back().unwrap(), 3);
+        iter.as_mut_slice()[0] = 10;
+        assert_eq!(iter.as_slice(), [10, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_values() {
+        use ::alloc::rc::Rc;
+
+        let value = Rc::new(());
+        let mut array = [const { core::mem::MaybeUninit::<Rc<()>>::uninit() }; 4];
+        let init = Uninit::from_maybe_uninit_slice(&mut array)
+            .init(crate::slice::RepeatInit::new(value.clone()));
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        let mut values = init.into_values();
+        let first = values.next().unwrap();
+        assert_eq!(values.len(), 3);
+        drop(values);
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(first);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_chunks_exact() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 7];
+        let mut chunks = Uninit::from_maybe_uninit_slice(&mut array).chunks_exact::<3>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder_len(), 1);
+        let first = chunks.next().unwrap().write([0, 1, 2]);
+        let second = chunks.next().unwrap().write([3, 4, 5]);
+        assert!(chunks.next().is_none());
+        let rem = chunks.into_remainder().write_array([6]);
+        assert_eq!((*first, *second, &*rem), ([0, 1, 2], [3, 4, 5], &[6][..]));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 6];
+        let mut iter = Uninit::from_maybe_uninit_slice(&mut array).into_iter();
+        let first = iter.next().unwrap().write(0);
+        let (left, right) = iter.split_at(2);
+        assert_eq!((left.len(), right.len()), (2, 3));
+        let left = left.map(|uninit| *uninit.write(1)).sum::<usize>();
+        let right = right.map(|uninit| *uninit.write(2)).sum::<usize>();
+        assert_eq!((*first, left, right), (0, 2, 6));
+    }
+
+    #[test]
+    fn test_zip_init() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 4];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+        let (init, uninit) = zip_init(uninit, [1, 2, 3]).for_each(|uninit, x| uninit.write(x * 2));
+        assert_eq!(*init, [2, 4, 6]);
+        assert_eq!(uninit.len(), 1);
+
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 2];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+        let (init, uninit, mut rest) = {
+            let mut zip = zip_init(uninit, 0..);
+            while zip.write_next(|uninit, x| uninit.write(x)) {}
+            zip.finish_with_data()
+        };
+        assert_eq!(
+            (&*init, uninit.len(), rest.next()),
+            (&[0, 1][..], 0, Some(2))
+        );
+    }
+
+    #[test]
+    fn test_pin_iter_mut() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 3];
+        let init = Uninit::from_maybe_uninit_slice(&mut array).write_array([0, 1, 2]);
+        let mut init = Pin::new(init);
+        for mut x in PinIterMut::new(init.as_mut()) {
+            *x += 1;
+        }
+        assert_eq!(*init, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_finish_mixed() {
+        let mut array = [core::mem::MaybeUninit::<usize>::uninit(); 5];
+        let mut iter = Uninit::from_maybe_uninit_slice(&mut array).into_iter();
+        let first = iter.next().unwrap().write(0);
+        let last = iter.next_back().unwrap().write(4);
+        let middle = iter.finish().write_array([1, 2, 3]);
+        assert_eq!((*first, &*middle, *last), (0, &[1, 2, 3][..], 4));
+
+        let mut iter = middle.into_iter();
+        drop(iter.next_back());
+        assert_eq!(*iter.finish(), [1, 2]);
+
+        let mut array = [core::mem::MaybeUninit::<()>::uninit(); 5];
+        let mut iter = Uninit::from_maybe_uninit_slice(&mut array).into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.finish().len(), 3);
+    }
+
+    #[test]
+    fn test_advance_by() {
+        let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+        let ptr = NonNull::from(&mut array);
+
+        // SAFETY: the array is allocated
+        let mut iter = unsafe { RawIter::new(ptr) };
+
+        assert_eq!(iter.advance_by(2), Ok(()));
+        assert_eq!(iter.advance_back_by(3), Ok(()));
+        assert_eq!(iter.len(), 3);
+        assert!(iter.nth(3).is_none());
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.advance_by(2), Err(NonZeroUsize::new(2).unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_init_nth_drops() {
+        use ::alloc::rc::Rc;
+
+        let value = Rc::new(());
+        let mut array = [const { core::mem::MaybeUninit::<Rc<()>>::uninit() }; 6];
+        let init = Uninit::from_maybe_uninit_slice(&mut array)
+            .init(crate::slice::RepeatInit::new(value.clone()));
+
+        let mut iter = init.into_iter();
+        drop(iter.nth(1));
+        drop(iter.nth_back(1));
+        assert_eq!(Rc::strong_count(&value), 3);
+        assert_eq!(iter.len(), 2);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
     #[test]
     fn test_rev_simple() {
         let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
@@ -2,39 +2,407 @@
 //!
 //! A layout provider is a type which can specify what layout to use for a given type `T`
 
-use core::alloc::{Layout, LayoutError};
+use core::{
+    alloc::{Layout, LayoutError},
+    marker::{PhantomData, Unsize},
+    ptr::{DynMetadata, Pointee},
+};
 
-use crate::traits::LayoutProvider;
+use crate::traits::{LayoutProvider, SizeHint};
+
+/// Derive a layout provider for a `#[repr(C)]` struct whose last field is a slice
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use ip_init::{layout::LayoutProvider, traits::LayoutProvider as _};
+///
+/// #[derive(LayoutProvider)]
+/// #[repr(C)]
+/// struct Packet {
+///     id: u64,
+///     flags: u8,
+///     data: [u16],
+/// }
+///
+/// let provider = PacketLayoutProvider::new(3);
+/// let layout = provider.layout_for(&()).unwrap();
+/// assert_eq!((layout.size(), layout.align()), (16, 8));
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub use ip_init_derive::LayoutProvider;
 
 /// a layout provider for sized types
 pub struct SizedLayoutProvider;
 
 // SAFETY: this implementation always returns an error, which is fine
-unsafe impl<T> LayoutProvider<T> for SizedLayoutProvider {
+unsafe impl<T, I: ?Sized> LayoutProvider<T, I> for SizedLayoutProvider {
     #[inline]
-    fn layout_for(&self) -> Result<Layout, LayoutError> {
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
         // 0 is never a valid alignment, so this always fails
         Ok(Layout::new::<T>())
     }
 
     #[inline]
-    fn cast(&self, ptr: *mut u8) -> *mut T {
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut T {
         ptr.cast()
     }
 }
 
+/// a layout provider which gets the layout from the initializer, see [`SizeHint`]
+#[derive(Debug, Clone, Copy)]
+pub struct InitLayoutProvider;
+
+// SAFETY: `SizeHint` guarantees that the initializer always gives equivalent layout providers
+unsafe impl<T: ?Sized, I: ?Sized + SizeHint<T>> LayoutProvider<T, I> for InitLayoutProvider {
+    #[inline]
+    fn layout_for(&self, init: &I) -> Result<Layout, LayoutError> {
+        init.layout_provider().layout_for(&())
+    }
+
+    #[inline]
+    fn cast(&self, init: &I, ptr: *mut u8) -> *mut T {
+        init.layout_provider().cast(&(), ptr)
+    }
+}
+
 /// a layout provider for slice types
 pub struct SliceLayoutProvider(pub usize);
 
 // SAFETY: this implementation always returns an error, which is fine
-unsafe impl<T> LayoutProvider<[T]> for SliceLayoutProvider {
+unsafe impl<T, I: ?Sized> LayoutProvider<[T], I> for SliceLayoutProvider {
     #[inline]
-    fn layout_for(&self) -> Result<Layout, LayoutError> {
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
         Layout::array::<T>(self.0)
     }
 
     #[inline]
-    fn cast(&self, ptr: *mut u8) -> *mut [T] {
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut [T] {
         core::ptr::slice_from_raw_parts_mut(ptr.cast(), self.0)
     }
 }
+
+/// a layout provider for string slices, with the given length in bytes
+pub struct StrLayoutProvider(pub usize);
+
+// SAFETY: the layout of `str` is the same as `[u8]` with the same length
+unsafe impl<I: ?Sized> LayoutProvider<str, I> for StrLayoutProvider {
+    #[inline]
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
+        Layout::array::<u8>(self.0)
+    }
+
+    #[inline]
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut str {
+        core::ptr::slice_from_raw_parts_mut(ptr, self.0) as *mut str
+    }
+}
+
+/// a layout provider for C strings, with the given length in bytes *including* the nul terminator
+pub struct CStrLayoutProvider(pub usize);
+
+// SAFETY: the layout of `CStr` is the same as `[u8]` with the same length
+unsafe impl<I: ?Sized> LayoutProvider<core::ffi::CStr, I> for CStrLayoutProvider {
+    #[inline]
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
+        Layout::array::<u8>(self.0)
+    }
+
+    #[inline]
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut core::ffi::CStr {
+        core::ptr::slice_from_raw_parts_mut(ptr, self.0) as *mut core::ffi::CStr
+    }
+}
+
+/// A dynamically sized struct which consists of a sized header followed by a slice
+///
+/// With the `derive` feature, [`derive(LayoutProvider)`](LayoutProvider) is a safe alternative
+/// which doesn't need a separate header type.
+///
+/// ```
+/// # use ip_init::layout::TailSlice;
+/// #[repr(C)]
+/// struct Node {
+///     header: u32,
+///     data: [u16],
+/// }
+///
+/// // SAFETY: `Node` is `#[repr(C)]`, with a `u32` header followed by a `[u16]`
+/// unsafe impl TailSlice for Node {
+///     type Header = u32;
+///     type Elem = u16;
+/// }
+/// ```
+///
+/// # Safety
+///
+/// `Self` must be a `#[repr(C)]` struct whose fields can be split into the leading
+/// fields, which have the same layout as `Self::Header` (for example a single field of type
+/// `Self::Header`), followed by a trailing field of type `[Self::Elem]`
+pub unsafe trait TailSlice: Pointee<Metadata = usize> {
+    /// The sized header of the struct
+    type Header;
+    /// The element type of the trailing slice
+    type Elem;
+}
+
+/// Compute the layout of a `#[repr(C)]` struct with a header `H` followed by `len` elements of `T`,
+/// and the offset of the trailing slice
+pub fn tail_slice_layout<H, T>(len: usize) -> Result<(Layout, usize), LayoutError> {
+    let (layout, offset) = Layout::new::<H>().extend(Layout::array::<T>(len)?)?;
+    Ok((layout.pad_to_align(), offset))
+}
+
+/// a layout provider for [`TailSlice`] types, with a header `H` followed by a slice of `T`s
+pub struct TailSliceLayoutProvider<H, T> {
+    len: usize,
+    _ty: PhantomData<fn() -> (H, T)>,
+}
+
+impl<H, T> TailSliceLayoutProvider<H, T> {
+    /// Create a new layout provider for a trailing slice of length `len`
+    pub const fn new(len: usize) -> Self {
+        Self {
+            len,
+            _ty: PhantomData,
+        }
+    }
+
+    /// The length of the trailing slice
+    pub const fn data_len(&self) -> usize {
+        self.len
+    }
+
+    /// The offset of the trailing slice in bytes from the start of the struct
+    pub fn data_offset(&self) -> Result<usize, LayoutError> {
+        tail_slice_layout::<H, T>(self.len).map(|(_, offset)| offset)
+    }
+}
+
+// SAFETY: the layout of a `TailSlice` is the same as a `#[repr(C)]` struct with a header
+// followed by a slice, and the metadata of a `TailSlice` is the length of the trailing slice
+unsafe impl<U: ?Sized + TailSlice<Header = H, Elem = T>, H, T, I: ?Sized> LayoutProvider<U, I>
+    for TailSliceLayoutProvider<H, T>
+{
+    #[inline]
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
+        tail_slice_layout::<H, T>(self.len).map(|(layout, _)| layout)
+    }
+
+    #[inline]
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut U {
+        core::ptr::from_raw_parts_mut(ptr, self.len)
+    }
+}
+
+/// a layout provider from an explicitly computed layout and pointer metadata
+/// (for example the length for slices, or `()` for sized types)
+pub struct RawLayoutProvider<T: ?Sized> {
+    layout: Layout,
+    metadata: <T as Pointee>::Metadata,
+}
+
+impl<T: ?Sized> RawLayoutProvider<T> {
+    /// Create a new layout provider
+    ///
+    /// # Safety
+    ///
+    /// The layout must be exactly the layout of a `T` with the given metadata,
+    /// because allocations such as `Box<T>` are deallocated with the layout of the `T`
+    pub const unsafe fn new(layout: Layout, metadata: <T as Pointee>::Metadata) -> Self {
+        Self { layout, metadata }
+    }
+
+    /// The layout that will be used for the allocation
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The metadata that will be used for the pointer
+    pub const fn metadata(&self) -> <T as Pointee>::Metadata {
+        self.metadata
+    }
+}
+
+impl<T: ?Sized> Clone for RawLayoutProvider<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for RawLayoutProvider<T> {}
+
+// SAFETY: the caller of `RawLayoutProvider::new` guarantees that `T` fits the layout
+unsafe impl<T: ?Sized, I: ?Sized> LayoutProvider<T, I> for RawLayoutProvider<T> {
+    #[inline]
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
+        Ok(self.layout)
+    }
+
+    #[inline]
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut T {
+        core::ptr::from_raw_parts_mut(ptr, self.metadata)
+    }
+}
+
+/// a layout provider for trait objects, using the layout stored in the vtable
+pub struct DynLayoutProvider<Dyn: ?Sized>(pub DynMetadata<Dyn>);
+
+impl<Dyn: ?Sized> DynLayoutProvider<Dyn> {
+    /// Create a new layout provider from the vtable of a trait object
+    pub const fn new(metadata: DynMetadata<Dyn>) -> Self {
+        Self(metadata)
+    }
+
+    /// Create a new layout provider for a trait object with the concrete type `T`
+    pub fn for_type<T: Unsize<Dyn>>() -> Self
+    where
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>>,
+    {
+        Self(core::ptr::metadata(core::ptr::null::<T>() as *const Dyn))
+    }
+}
+
+impl<Dyn: ?Sized> Clone for DynLayoutProvider<Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Dyn: ?Sized> Copy for DynLayoutProvider<Dyn> {}
+
+// SAFETY: the layout of a trait object is the layout stored in it's vtable
+unsafe impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, I: ?Sized> LayoutProvider<Dyn, I>
+    for DynLayoutProvider<Dyn>
+{
+    #[inline]
+    fn layout_for(&self, _init: &I) -> Result<Layout, LayoutError> {
+        Ok(self.0.layout())
+    }
+
+    #[inline]
+    fn cast(&self, _init: &I, ptr: *mut u8) -> *mut Dyn {
+        core::ptr::from_raw_parts_mut(ptr, self.0)
+    }
+}
+
+/// a layout provider which rounds the size of the allocation up to a multiple of `multiple`,
+/// while keeping the layout of the value intact
+///
+/// This is useful to avoid false sharing between neighbouring allocations, by padding them to a
+/// cache line. NOTE: a `Box` is deallocated with the layout of it's value, so the `boxed`
+/// functions reject padded layouts. This is meant for allocations which are never deallocated
+/// with the layout of the value, like `arena::emplace_in` or a [`RawPlace`](crate::place::RawPlace)
+#[derive(Debug, Clone, Copy)]
+pub struct PaddedLayoutProvider<L> {
+    inner: L,
+    multiple: usize,
+}
+
+impl<L> PaddedLayoutProvider<L> {
+    /// Create a new padded layout provider
+    ///
+    /// # Panics
+    ///
+    /// if `multiple` is not a power of two
+    pub fn new(inner: L, multiple: usize) -> Self {
+        assert!(multiple.is_power_of_two());
+        Self { inner, multiple }
+    }
+
+    /// The multiple that the size will be rounded up to
+    pub fn multiple(&self) -> usize {
+        self.multiple
+    }
+
+    /// Get the inner layout provider
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+}
+
+// SAFETY: the padded layout has the same alignment as the inner layout and a larger size,
+// so T still fits in it
+unsafe impl<T: ?Sized, I: ?Sized, L: LayoutProvider<T, I>> LayoutProvider<T, I>
+    for PaddedLayoutProvider<L>
+{
+    #[inline]
+    fn layout_for(&self, init: &I) -> Result<Layout, LayoutError> {
+        let layout = self.inner.layout_for(init)?;
+        let size = layout.size().checked_next_multiple_of(self.multiple);
+        // if the size overflows, then `from_size_align` will fail the same way
+        Layout::from_size_align(size.unwrap_or(usize::MAX), layout.align())
+    }
+
+    #[inline]
+    fn cast(&self, init: &I, ptr: *mut u8) -> *mut T {
+        self.inner.cast(init, ptr)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::{func::InitFn, Uninit};
+    use alloc::{boxed::Box, format};
+    use core::fmt::Debug;
+
+    #[test]
+    fn test_emplace_dyn() {
+        let provider = DynLayoutProvider::<dyn Debug>::for_type::<[u32; 2]>();
+        let boxed: Box<dyn Debug> = crate::boxed::emplace(
+            provider,
+            InitFn::new(|mut uninit: Uninit<dyn Debug>| {
+                // SAFETY: the slot was allocated with the vtable of `[u32; 2]`
+                let slot = unsafe { Uninit::from_raw(uninit.as_mut_ptr().cast::<[u32; 2]>()) };
+                slot.write([1, 2]).into_raw();
+                // SAFETY: the slot was initialized with a `[u32; 2]`, which matches the vtable
+                unsafe { uninit.assume_init() }
+            }),
+        );
+        assert_eq!(format!("{boxed:?}"), "[1, 2]");
+    }
+
+    #[test]
+    fn test_padded() {
+        let provider = PaddedLayoutProvider::new(SliceLayoutProvider(3), 64);
+        let layout = LayoutProvider::<[u16]>::layout_for(&provider, &()).unwrap();
+        assert_eq!((layout.size(), layout.align()), (64, 2));
+
+        let boxed = crate::boxed::try_emplace(provider, crate::slice::RepeatInit::new(0u16));
+        assert!(matches!(
+            boxed,
+            Err(crate::boxed::AllocError::LayoutMismatch { .. })
+        ));
+
+        let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); 63];
+        let mut place = crate::place::RawPlace::from_maybe_uninit_bytes(&mut buffer);
+        let err = place.try_emplace(PaddedLayoutProvider::new(SizedLayoutProvider, 64), 0u8);
+        assert!(matches!(
+            err,
+            Err(crate::place::PlaceError::DoesNotFit { .. })
+        ));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_layout_provider() {
+        #[derive(super::LayoutProvider)]
+        #[repr(C)]
+        struct Packet<T> {
+            id: u64,
+            flags: u8,
+            data: [T],
+        }
+
+        for len in [0, 1, 7, 100] {
+            let provider = PacketLayoutProvider::new(len);
+            let layout = LayoutProvider::<Packet<u16>>::layout_for(&provider, &()).unwrap();
+            let ptr: *mut Packet<u16> = provider.cast(&(), core::ptr::NonNull::dangling().as_ptr());
+            // SAFETY: the metadata is a valid length, and the size fits in an `isize`
+            let expected = unsafe { Layout::for_value_raw(ptr) };
+            assert_eq!(layout, expected);
+            assert_eq!(core::ptr::metadata(ptr), len);
+        }
+    }
+}
@@ -1,6 +1,20 @@
 //! Init is a crate that handles fallible in-place initialization
 
-#![feature(slice_ptr_len, dropck_eyepatch, strict_provenance)]
+#![feature(
+    slice_ptr_len,
+    dropck_eyepatch,
+    strict_provenance,
+    trusted_len,
+    iter_advance_by,
+    ptr_metadata,
+    unsize
+)]
+#![cfg_attr(feature = "alloc", feature(layout_for_ptr))]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(
+    all(feature = "alloc", not(no_global_oom_handling)),
+    feature(unique_rc_arc)
+)]
 #![forbid(
     clippy::undocumented_unsafe_blocks,
     clippy::missing_safety_doc,
@@ -18,26 +32,88 @@ extern crate std;
 #[cfg(feature = "std")]
 extern crate std as alloc;
 
+// lets the derive macros refer to this crate as `::ip_init` in it's own tests
+extern crate self as ip_init;
+
 #[doc(hidden)]
 pub mod macros;
 
 mod ptr;
 
+/// the panic for a failed initializer, in functions which don't return the error
+#[cold]
+#[inline(never)]
+pub(crate) fn init_failed<E: core::fmt::Debug>(err: E) -> ! {
+    panic!("Failed to initialize value: {err:?}")
+}
+
+/// Derive an initializer which initializes a struct field by field
+///
+/// The error type has a `CamelCase` variant for each field, so the field names must
+/// map to distinct variants
+///
+/// ```compile_fail
+/// #[derive(ip_init::Init)]
+/// struct Conflict {
+///     a_b: u8,
+///     aB: u8,
+/// }
+/// ```
+///
+/// And the fields of a packed struct can't be initialized in place
+///
+/// ```compile_fail
+/// #[derive(ip_init::Init)]
+/// #[repr(packed)]
+/// struct Packed {
+///     a: u8,
+///     b: u32,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use ip_init_derive::Init;
 pub use pin_ptr::{PinnedInit, PinnedUninit};
 pub use ptr::{Init, Uninit};
 pub mod pin_ptr;
+pub mod pin_slot;
+pub mod project;
 
 pub mod func;
 pub mod raw;
+pub mod scope;
 pub mod traits;
 
 pub mod array;
 pub mod iter;
 pub mod slice;
+pub mod str;
 
 pub mod layout;
 
+pub mod cell;
+pub mod inline_vec;
+pub mod place;
+pub mod pool;
+
+pub mod zeroed;
+
+#[cfg(all(
+    feature = "alloc",
+    not(no_global_oom_handling),
+    target_has_atomic = "ptr"
+))]
+pub mod arc;
+#[cfg(feature = "allocator_api")]
+pub mod arena;
 #[cfg(feature = "alloc")]
 pub mod boxed;
+#[cfg(feature = "alloc")]
+pub mod emplace;
+#[cfg(all(feature = "alloc", not(no_global_oom_handling)))]
+pub mod rc;
+#[cfg(all(feature = "alloc", not(no_global_oom_handling)))]
+pub mod slab;
+#[cfg(all(feature = "alloc", not(no_global_oom_handling)))]
+pub mod vec;
 
 pub mod pin;
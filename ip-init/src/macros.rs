@@ -1,5 +1,13 @@
 pub use core;
 
+// INTERNAL to the `LayoutProvider` derive
+pub fn tail_slice_from_raw_parts<T: ?Sized + core::ptr::Pointee<Metadata = usize>>(
+    ptr: *mut u8,
+    len: usize,
+) -> *mut T {
+    core::ptr::from_raw_parts_mut(ptr, len)
+}
+
 // SAFETY: INTERNAL to project
 pub unsafe fn bind<'a, T: ?Sized, U: ?Sized>(
     _: &'a mut crate::Uninit<T>,
@@ -9,6 +17,15 @@ pub unsafe fn bind<'a, T: ?Sized, U: ?Sized>(
     unsafe { crate::Uninit::from_raw(ptr) }
 }
 
+// SAFETY: INTERNAL to project_all
+pub unsafe fn bind_field<'a, T: ?Sized, U: ?Sized>(
+    _: &crate::Uninit<'a, T>,
+    ptr: *mut U,
+) -> crate::Uninit<'a, U> {
+    // SAFETY: only used in project_all, which consumes the `Uninit`
+    unsafe { crate::Uninit::from_raw(ptr) }
+}
+
 // SAFETY: INTERNAL to project
 pub unsafe fn bind_pin<'a, T: ?Sized, U: ?Sized>(
     _: &'a mut crate::PinnedUninit<T>,
@@ -19,25 +36,470 @@ pub unsafe fn bind_pin<'a, T: ?Sized, U: ?Sized>(
 }
 
 /// Create an uninit stack slot
+///
+/// Several slots can be declared at once, and the type may be left out to infer it.
+/// Without a name, this is an expression which creates the slot in a temporary, so it
+/// can only be used until the end of the enclosing statement.
+///
+/// ```
+/// ip_init::slot!(a: u32, b: [u8; 4]);
+/// let a = a.write(10);
+/// let b = b.init(ip_init::zeroed::ZeroInit);
+/// assert_eq!((*a, *b), (10, [0; 4]));
+///
+/// assert_eq!(*ip_init::slot!(u64).write(20), 20);
+/// assert_eq!(*ip_init::slot!().write(30u8), 30);
+/// ```
 #[macro_export]
 macro_rules! slot {
-    ($name:ident : $($type:ty)?) => {
+    () => {
+        $crate::Uninit::from_maybe_uninit(&mut $crate::macros::core::mem::MaybeUninit::uninit())
+    };
+    ($($name:ident : $($type:ty)?),+ $(,)?) => {$(
         let mut $name = $crate::macros::core::mem::MaybeUninit$(::<$type>)?::uninit();
         let $name = $crate::Uninit::from_maybe_uninit(&mut $name);
+    )+};
+    ($type:ty) => {
+        $crate::Uninit::from_maybe_uninit(
+            &mut $crate::macros::core::mem::MaybeUninit::<$type>::uninit(),
+        )
     };
 }
 
 /// Create an pinned uninit stack slot
+///
+/// With an initializer, the slot is initialized in place and bound as a `Pin<&mut T>`,
+/// like [`stack_emplace!`](crate::stack_emplace). Prefix the initializer with `try`
+/// to use a fallible initializer, the error is propagated with `?`.
+///
+/// ```
+/// # fn main() -> Result<(), ip_init::slice::SliceIterInitError<core::convert::Infallible>> {
+/// ip_init::slot_pin!(empty: [u32; 4]);
+/// let _: ip_init::PinnedUninit<[u32; 4]> = empty;
+///
+/// ip_init::slot_pin!(zeroed: [u32; 4] = ip_init::zeroed::ZeroInit);
+/// assert_eq!(*zeroed, [0; 4]);
+///
+/// ip_init::slot_pin!(counted: [u32; 4] = try ip_init::slice::SliceIterInit::new(0..));
+/// assert_eq!(*counted, [0, 1, 2, 3]);
+/// # Ok(())
+/// # }
+/// ```
 #[macro_export]
 macro_rules! slot_pin {
     ($name:ident : $($type:ty)?) => {
         let mut $name = $crate::macros::core::mem::MaybeUninit$(::<$type>)?::uninit();
+        // SAFETY: the storage is shadowed, so it can't be moved
         let $name = unsafe { $crate::macros::core::pin::Pin::new_unchecked(&mut $name) };
         let $name = $crate::PinnedUninit::from_maybe_uninit($name);
     };
+    ($name:ident : $($type:ty)? = try $init:expr) => {
+        $crate::stack_emplace!(try $name $(: $type)? = $init);
+    };
+    ($name:ident : $($type:ty)? = $init:expr) => {
+        $crate::stack_emplace!($name $(: $type)? = $init);
+    };
+}
+
+// SAFETY: INTERNAL to stack_emplace
+pub unsafe fn pinned_init_as_mut<'b, T: ?Sized>(
+    init: &'b mut crate::PinnedInit<'_, T>,
+) -> core::pin::Pin<&'b mut T> {
+    // SAFETY: only used in stack_emplace, which never moves out of the `PinnedInit`
+    // and always drops it before it's storage goes out of scope
+    unsafe { init.as_mut() }
+}
+
+/// Create a pinned stack slot, initialize it in place, and bind it as a `Pin<&mut T>`
+///
+/// The value is dropped when it goes out of scope, before it's storage is released.
+/// Prefix the binding with `try` to use a fallible initializer, the error is propagated
+/// with `?`.
+///
+/// ```
+/// # use core::pin::Pin;
+/// # fn main() -> Result<(), ()> {
+/// ip_init::stack_emplace!(array: [u8; 4096] = ip_init::zeroed::ZeroInit);
+/// let _: Pin<&mut [u8; 4096]> = array;
+///
+/// let init = ip_init::func::TryPinInitFn::new(|uninit: ip_init::PinnedUninit<u32>| {
+///     Ok::<_, ()>(uninit.write(10))
+/// });
+/// ip_init::stack_emplace!(try value: u32 = init);
+/// assert_eq!(*value, 10);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! stack_emplace {
+    (try $name:ident $(: $type:ty)? = $init:expr) => {
+        $crate::slot_pin!($name: $($type)?);
+        let mut $name = $name.try_init($init)?;
+        // SAFETY: the `PinnedInit` is shadowed, so it can't be moved or forgotten, and it's
+        // declared after it's storage, so it's dropped first
+        let $name = unsafe { $crate::macros::pinned_init_as_mut(&mut $name) };
+    };
+    ($name:ident $(: $type:ty)? = $init:expr) => {
+        $crate::slot_pin!($name: $($type)?);
+        let mut $name = $name.init($init);
+        // SAFETY: the `PinnedInit` is shadowed, so it can't be moved or forgotten, and it's
+        // declared after it's storage, so it's dropped first
+        let $name = unsafe { $crate::macros::pinned_init_as_mut(&mut $name) };
+    };
+}
+
+// INTERNAL to init, the closure is only type checked, never called
+pub fn check_fields<T>(_: *mut T, _: impl FnOnce(&T)) {}
+
+// INTERNAL to init, gives later fields access to the earlier fields
+pub trait FieldRef {
+    type Ref<'a>
+    where
+        Self: 'a;
+
+    fn field_ref(&mut self) -> Self::Ref<'_>;
+}
+
+impl<T: ?Sized> FieldRef for crate::Init<'_, T> {
+    type Ref<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    fn field_ref(&mut self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> FieldRef for crate::PinnedInit<'_, T> {
+    type Ref<'a>
+        = *mut T
+    where
+        Self: 'a;
+
+    fn field_ref(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+}
+
+/// Create an initializer which initializes a struct field by field
+///
+/// Fields are initialized in order, `field: value` writes a value, `field <- init` runs a
+/// nested [`Initialize`](crate::traits::Initialize)r in place, and `field` is shorthand for
+/// `field: field`. Every field must be listed exactly once. If a field panics, the fields
+/// which were already initialized are dropped.
+///
+/// The field expressions are evaluated in order when the initializer runs, not when it's
+/// created, and the variables they use are moved into the initializer. Fields which are
+/// already initialized can be accessed by name from the later field expressions, as a
+/// shared reference.
+///
+/// ```
+/// # use ip_init::Uninit;
+/// struct Buffer {
+///     len: usize,
+///     data: [u8; 4096],
+///     name: &'static str,
+/// }
+///
+/// let name = "zeroed";
+/// let init = ip_init::init!(Buffer {
+///     len: 0,
+///     data <- ip_init::zeroed::ZeroInit,
+///     name,
+/// });
+///
+/// ip_init::slot!(slot: Buffer);
+/// let buffer = slot.init(init);
+/// assert_eq!(buffer.len, 0);
+/// assert_eq!(buffer.data, [0; 4096]);
+/// assert_eq!(buffer.name, "zeroed");
+/// ```
+#[macro_export]
+macro_rules! init {
+    ($type:path { $($fields:tt)* }) => {
+        $crate::func::InitFn::new(move |mut uninit: $crate::Uninit<$type>| {
+            let ptr = uninit.as_mut_ptr();
+            $crate::init!(@fields init, $type, ptr, []; $($fields)*);
+            // SAFETY: every field was initialized above
+            unsafe { uninit.assume_init() }
+        })
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, [$($done:ident)*];) => {
+        // every field must be listed exactly once, and none may be unaligned
+        $crate::macros::check_fields($ptr, |value: &$type| {
+            let $type { $($done: _),* } = value;
+            $(let _ = &value.$done;)*
+        });
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, [$($done:ident)*]; $field:ident : $value:expr $(, $($rest:tt)*)?) => {
+        // SAFETY: `$ptr` points to an uninit struct, and the field is aligned
+        let field = unsafe {
+            $crate::Uninit::from_raw($crate::macros::core::ptr::addr_of_mut!((*$ptr).$field))
+        };
+        let mut guard = field.write($value);
+        #[allow(unused_variables)]
+        let $field = $crate::macros::FieldRef::field_ref(&mut guard);
+        $crate::init!(@fields $mode, $type, $ptr, [$($done)* $field]; $($($rest)*)?);
+        $crate::macros::core::mem::forget(guard);
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, [$($done:ident)*]; $field:ident <- $init:expr $(, $($rest:tt)*)?) => {
+        // SAFETY: `$ptr` points to an uninit struct, and the field is aligned
+        let field = unsafe {
+            $crate::Uninit::from_raw($crate::macros::core::ptr::addr_of_mut!((*$ptr).$field))
+        };
+        let mut guard = $crate::init!(@nested $mode, field, $init);
+        #[allow(unused_variables)]
+        let $field = $crate::macros::FieldRef::field_ref(&mut guard);
+        $crate::init!(@fields $mode, $type, $ptr, [$($done)* $field]; $($($rest)*)?);
+        $crate::macros::core::mem::forget(guard);
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, [$($done:ident)*]; $field:ident $(, $($rest:tt)*)?) => {
+        $crate::init!(@fields $mode, $type, $ptr, [$($done)*]; $field: $field $(, $($rest)*)?);
+    };
+    (@nested init, $field:expr, $init:expr) => {
+        $field.init($init)
+    };
+    (@nested try, $field:expr, $init:expr) => {
+        $field.try_init($init)?
+    };
+}
+
+/// Create a fallible initializer which initializes a struct field by field
+///
+/// This is the fallible version of [`init!`](crate::init), `field <- init` takes a
+/// [`TryInitialize`](crate::traits::TryInitialize)r, and field expressions may use `?`.
+/// Errors are converted into the error type of the initializer with `From`, which
+/// may be given after the struct, or is inferred otherwise. If a field fails or panics,
+/// the fields which were already initialized are dropped.
+///
+/// ```
+/// # use ip_init::Uninit;
+/// # use core::num::ParseIntError;
+/// struct Config {
+///     port: u16,
+///     name: &'static str,
+///     data: [u8; 4],
+/// }
+///
+/// #[derive(Debug)]
+/// enum ConfigError {
+///     Port(ParseIntError),
+///     Data(ip_init::slice::SliceIterInitError<core::convert::Infallible>),
+/// }
+/// # impl From<ParseIntError> for ConfigError {
+/// #     fn from(err: ParseIntError) -> Self { Self::Port(err) }
+/// # }
+/// # impl From<ip_init::slice::SliceIterInitError<core::convert::Infallible>> for ConfigError {
+/// #     fn from(err: ip_init::slice::SliceIterInitError<core::convert::Infallible>) -> Self {
+/// #         Self::Data(err)
+/// #     }
+/// # }
+///
+/// let init = ip_init::try_init!(Config {
+///     port: "8080".parse()?,
+///     name: "server",
+///     data <- ip_init::slice::SliceIterInit::new(0..),
+/// }? ConfigError);
+///
+/// ip_init::slot!(slot: Config);
+/// let config = slot.try_init(init).unwrap();
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(config.data, [0, 1, 2, 3]);
+///
+/// let init = ip_init::try_init!(Config {
+///     port: "port".parse()?,
+///     name: "server",
+///     data: [0; 4],
+/// }? ConfigError);
+///
+/// assert!(matches!(
+///     ip_init::slot!(Config).try_init(init),
+///     Err(ConfigError::Port(_))
+/// ));
+/// ```
+#[macro_export]
+macro_rules! try_init {
+    ($type:path { $($fields:tt)* } $(? $error:ty)?) => {
+        $crate::func::TryInitFn::new::<$crate::try_init!(@error $($error)?)>(
+            move |mut uninit: $crate::Uninit<$type>| {
+                let ptr = uninit.as_mut_ptr();
+                $crate::init!(@fields try, $type, ptr, []; $($fields)*);
+                // SAFETY: every field was initialized above
+                $crate::macros::core::result::Result::Ok(unsafe { uninit.assume_init() })
+            },
+        )
+    };
+    (@error) => { _ };
+    (@error $error:ty) => { $error };
+}
+
+/// Create a pinned initializer which initializes a struct field by field
+///
+/// This is the pinned version of [`init!`](crate::init), the struct must implement
+/// [`ProjectUninit`](crate::project::ProjectUninit). Structurally pinned fields are
+/// initialized through a [`PinnedUninit`](crate::PinnedUninit), so `field <- init` takes a
+/// [`PinInitialize`](crate::traits::PinInitialize)r for `#[pin]` fields, and an
+/// [`Initialize`](crate::traits::Initialize)r for all other fields. If a field panics,
+/// the fields which were already initialized are dropped.
+///
+/// Earlier fields can be accessed by name from the later field expressions, like in
+/// `init!`, except that structurally pinned fields are accessed as a `*mut` pointer,
+/// so they can be used to create self-referential structs.
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use core::marker::PhantomPinned;
+/// use ip_init::project::ProjectUninit;
+///
+/// #[derive(ProjectUninit)]
+/// struct Timer {
+///     #[pin]
+///     node: PhantomPinned,
+///     #[pin]
+///     buffer: [u8; 64],
+///     deadline: u64,
+/// }
+///
+/// let init = ip_init::pin_init!(Timer {
+///     node: PhantomPinned,
+///     buffer <- ip_init::zeroed::ZeroInit,
+///     deadline: 10,
+/// });
+///
+/// ip_init::stack_emplace!(timer: Timer = init);
+/// assert_eq!(timer.deadline, 10);
+/// assert_eq!(timer.buffer, [0; 64]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pin_init {
+    ($type:path { $($fields:tt)* }) => {
+        $crate::func::PinInitFn::new(move |mut uninit: $crate::PinnedUninit<$type>| {
+            let ptr = uninit.as_mut_ptr();
+            let fields = $crate::project::ProjectUninit::project_pinned_uninit(&mut uninit);
+            $crate::pin_init!(@fields init, $type, ptr, fields, []; $($fields)*);
+            // SAFETY: every field was initialized above
+            unsafe { uninit.assume_init() }
+        })
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, $fields:ident, [$($done:ident)*];) => {
+        // every field must be listed exactly once
+        $crate::macros::check_fields($ptr, |value: &$type| {
+            let $type { $($done: _),* } = value;
+        });
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, $fields:ident, [$($done:ident)*]; $field:ident : $value:expr $(, $($rest:tt)*)?) => {
+        let mut guard = $fields.$field.write($value);
+        #[allow(unused_variables)]
+        let $field = $crate::macros::FieldRef::field_ref(&mut guard);
+        $crate::pin_init!(@fields $mode, $type, $ptr, $fields, [$($done)* $field]; $($($rest)*)?);
+        $crate::macros::core::mem::forget(guard);
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, $fields:ident, [$($done:ident)*]; $field:ident <- $init:expr $(, $($rest:tt)*)?) => {
+        let mut guard = $crate::init!(@nested $mode, $fields.$field, $init);
+        #[allow(unused_variables)]
+        let $field = $crate::macros::FieldRef::field_ref(&mut guard);
+        $crate::pin_init!(@fields $mode, $type, $ptr, $fields, [$($done)* $field]; $($($rest)*)?);
+        $crate::macros::core::mem::forget(guard);
+    };
+    (@fields $mode:ident, $type:path, $ptr:ident, $fields:ident, [$($done:ident)*]; $field:ident $(, $($rest:tt)*)?) => {
+        $crate::pin_init!(@fields $mode, $type, $ptr, $fields, [$($done)*]; $field: $field $(, $($rest)*)?);
+    };
+}
+
+/// Create a fallible pinned initializer which initializes a struct field by field
+///
+/// This is the pinned version of [`try_init!`](crate::try_init), see
+/// [`pin_init!`](crate::pin_init) for how pinned fields are initialized.
+#[macro_export]
+macro_rules! try_pin_init {
+    ($type:path { $($fields:tt)* } $(? $error:ty)?) => {
+        $crate::func::TryPinInitFn::new::<$crate::try_init!(@error $($error)?)>(
+            move |mut uninit: $crate::PinnedUninit<$type>| {
+                let ptr = uninit.as_mut_ptr();
+                let fields = $crate::project::ProjectUninit::project_pinned_uninit(&mut uninit);
+                $crate::pin_init!(@fields try, $type, ptr, fields, []; $($fields)*);
+                // SAFETY: every field was initialized above
+                $crate::macros::core::result::Result::Ok(unsafe { uninit.assume_init() })
+            },
+        )
+    };
+}
+
+/// Create a static which is initialized in place on first access
+///
+/// The initializer expression is evaluated on first access, and must implement
+/// [`Initialize`](crate::traits::Initialize) for the type of the static.
+/// The static derefs to it's value, see [`StaticLazy`](crate::cell::StaticLazy).
+///
+/// ```
+/// ip_init::static_emplace! {
+///     static SQUARES: [u64; 1024] = ip_init::array::ArrayFromFn::new(|i| (i * i) as u64);
+/// }
+///
+/// assert_eq!(SQUARES[12], 144);
+/// ```
+#[macro_export]
+macro_rules! static_emplace {
+    ($($(#[$meta:meta])* $vis:vis static $name:ident : $type:ty = $init:expr;)*) => {$(
+        $(#[$meta])*
+        $vis static $name: $crate::cell::StaticLazy<$type> = $crate::cell::StaticLazy::new(|uninit| {
+            uninit.init($init)
+        });
+    )*};
 }
 
 /// Project a uninit ptr to one of it's fields
+///
+/// The field may be a path to a field of a nested struct, like `inner.field`, and tuple
+/// structs and tuples are projected by index, like `0` or `inner.1`.
+///
+/// ```
+/// struct Outer {
+///     inner: Inner,
+///     len: usize,
+/// }
+///
+/// struct Inner {
+///     value: u32,
+/// }
+///
+/// ip_init::slot!(slot: Outer);
+/// let mut uninit = slot;
+/// ip_init::project!(Outer, uninit, inner.value).write(10);
+/// ip_init::project!(Outer, uninit, len).write(1);
+/// // SAFETY: every field was initialized
+/// let outer = unsafe { uninit.assume_init() };
+/// assert_eq!(outer.inner.value, 10);
+///
+/// ip_init::slot!(slot: (u8, (u16, u32)));
+/// let mut uninit = slot;
+/// ip_init::project!((u8, (u16, u32)), uninit, 0).write(1);
+/// ip_init::project!((u8, (u16, u32)), uninit, 1.0).write(2);
+/// ip_init::project!((u8, (u16, u32)), uninit, 1.1).write(3);
+/// // SAFETY: every field was initialized
+/// let tuple = unsafe { uninit.assume_init() };
+/// assert_eq!(*tuple, (1, (2, 3)));
+/// ```
+///
+/// The fields of packed structs may be unaligned, so they can't be projected
+///
+/// ```compile_fail
+/// struct Outer {
+///     inner: Packed,
+/// }
+///
+/// #[repr(packed)]
+/// struct Packed {
+///     tag: u8,
+///     value: u32,
+/// }
+///
+/// ip_init::slot!(slot: Outer);
+/// let mut uninit = slot;
+/// ip_init::project!(Outer, uninit, inner.value).write(10);
+/// ```
 #[macro_export]
 macro_rules! project {
     ($type:path, $uninit:expr, $field:ident) => {
@@ -46,22 +508,55 @@ macro_rules! project {
                 let _: $crate::Uninit<$type> = *uninit;
 
                 if false {
+                    // SAFETY: never run, this checks that `$field` is a field of `$type`
                     unsafe {
                         let $type { $field: _, .. } = (*uninit.as_mut_ptr());
                     }
                 }
 
+                // references to fields of packed structs don't compile, since they may be unaligned
+                $crate::macros::check_fields(uninit.as_mut_ptr(), |value: &$type| {
+                    let _ = &value.$field;
+                });
+
+                // SAFETY: the path only goes through fields, so it's in bounds of `uninit`
                 let projected = unsafe {
                     $crate::macros::core::ptr::addr_of_mut!((*uninit.as_mut_ptr()).$field)
                 };
 
+                // SAFETY: the projected pointer is borrowed from `uninit`
+                unsafe { $crate::macros::bind(uninit, projected) }
+            }
+        }
+    };
+    ($type:ty, $uninit:expr, $($field:tt).+) => {
+        match $uninit {
+            ref mut uninit => {
+                let _: $crate::Uninit<$type> = *uninit;
+
+                // `offset_of` only accepts fields, so the path can't go through a `Deref` impl
+                let _ = $crate::macros::core::mem::offset_of!($type, $($field).+);
+                // references to fields of packed structs don't compile, since they may be unaligned
+                $crate::macros::check_fields(uninit.as_mut_ptr(), |value: &$type| {
+                    let _ = &value $(.$field)+;
+                });
+
+                // SAFETY: the path only goes through fields, so it's in bounds of `uninit`
+                let projected = unsafe {
+                    $crate::macros::core::ptr::addr_of_mut!((*uninit.as_mut_ptr()) $(.$field)+)
+                };
+
+                // SAFETY: the projected pointer is borrowed from `uninit`
                 unsafe { $crate::macros::bind(uninit, projected) }
             }
         }
     };
 }
 
-/// Project a uninit ptr to one of it's fields
+/// Project a pinned uninit ptr to one of it's fields
+///
+/// The field may be a path to a field of a nested struct, like `inner.field`, and tuple
+/// structs and tuples are projected by index, like `0` or `inner.1`.
 #[macro_export]
 macro_rules! project_pin {
     ($type:path, $uninit:expr, $field:ident) => {
@@ -70,17 +565,211 @@ macro_rules! project_pin {
                 let _: $crate::PinnedUninit<$type> = *uninit;
 
                 if false {
+                    // SAFETY: never run, this checks that `$field` is a field of `$type`
                     unsafe {
                         let $type { $field: _, .. } = *uninit.as_mut_ptr();
                     }
                 }
 
+                // references to fields of packed structs don't compile, since they may be unaligned
+                $crate::macros::check_fields(uninit.as_mut_ptr(), |value: &$type| {
+                    let _ = &value.$field;
+                });
+
+                // SAFETY: the path only goes through fields, so it's in bounds of `uninit`
                 let projected = unsafe {
                     $crate::macros::core::ptr::addr_of_mut!((*uninit.as_mut_ptr()).$field)
                 };
 
+                // SAFETY: the projected pointer is borrowed from `uninit`
                 unsafe { $crate::macros::bind_pin(uninit, projected) }
             }
         }
     };
+    ($type:ty, $uninit:expr, $($field:tt).+) => {
+        match $uninit {
+            ref mut uninit => {
+                let _: $crate::PinnedUninit<$type> = *uninit;
+
+                // `offset_of` only accepts fields, so the path can't go through a `Deref` impl
+                let _ = $crate::macros::core::mem::offset_of!($type, $($field).+);
+                // references to fields of packed structs don't compile, since they may be unaligned
+                $crate::macros::check_fields(uninit.as_mut_ptr(), |value: &$type| {
+                    let _ = &value $(.$field)+;
+                });
+
+                // SAFETY: the path only goes through fields, so it's in bounds of `uninit`
+                let projected = unsafe {
+                    $crate::macros::core::ptr::addr_of_mut!((*uninit.as_mut_ptr()) $(.$field)+)
+                };
+
+                // SAFETY: the projected pointer is borrowed from `uninit`
+                unsafe { $crate::macros::bind_pin(uninit, projected) }
+            }
+        }
+    };
+}
+
+/// Split a uninit ptr into a uninit ptr for each of it's fields
+///
+/// This consumes the uninit ptr, and returns a struct with a [`Uninit`](crate::Uninit)
+/// for each field and a [`ProjectedUninit`](crate::project::ProjectedUninit), which can
+/// be turned back into an [`Init`](crate::Init) once every field is initialized. Every field
+/// must be listed exactly once, so no field can be projected twice or forgotten.
+///
+/// ```
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// ip_init::slot!(slot: Point);
+/// let (fields, uninit) = ip_init::project_all!(Point, slot, { x, y });
+/// fields.x.write(1);
+/// fields.y.write(2);
+/// // SAFETY: every field was initialized
+/// let point = unsafe { uninit.assume_init() };
+/// assert_eq!((point.x, point.y), (1, 2));
+/// ```
+#[macro_export]
+macro_rules! project_all {
+    ($type:path, $uninit:expr, { $($field:ident),* $(,)? }) => {{
+        let mut uninit: $crate::Uninit<'_, $type> = $uninit;
+        let ptr = uninit.as_mut_ptr();
+
+        // every field must be listed exactly once, and none may be unaligned
+        $crate::macros::check_fields(ptr, |value: &$type| {
+            let $type { $($field: _),* } = value;
+            $(let _ = &value.$field;)*
+        });
+
+        #[allow(non_camel_case_types)]
+        struct Fields<$($field),*> {
+            $($field: $field,)*
+        }
+
+        let fields = Fields {$(
+            // SAFETY: `ptr` points to an uninit struct, the field is aligned, and
+            // it's only projected once because `uninit` is consumed
+            $field: unsafe {
+                $crate::macros::bind_field(
+                    &uninit,
+                    $crate::macros::core::ptr::addr_of_mut!((*ptr).$field),
+                )
+            },
+        )*};
+
+        (fields, $crate::project::ProjectedUninit::new(uninit))
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use core::cell::Cell;
+
+    struct Counted<'a>(&'a Cell<u32>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    struct Triple<'a> {
+        first: Counted<'a>,
+        second: Counted<'a>,
+        third: Counted<'a>,
+    }
+
+    fn explode<T>() -> T {
+        panic!("explode")
+    }
+
+    #[test]
+    fn test_init_panic_drops_initialized_fields() {
+        let drops = &Cell::new(0);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let init = crate::init!(Triple {
+                first: Counted(drops),
+                second <- crate::func::InitFn::new(|uninit: crate::Uninit<Counted>| {
+                    uninit.write(Counted(drops))
+                }),
+                third: explode(),
+            });
+            crate::slot!(slot: Triple);
+            slot.init(init);
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn test_try_init_error_drops_initialized_fields() {
+        let drops = &Cell::new(0);
+
+        let init = crate::try_init!(Triple {
+            first: Counted(drops),
+            second <- crate::func::TryInitFn::new(|_: crate::Uninit<Counted>| Err("second")),
+            third: Counted(drops),
+        });
+        crate::slot!(slot: Triple);
+        let result: Result<_, &str> = slot.try_init(init).map(drop);
+
+        assert_eq!(result, Err("second"));
+        assert_eq!(drops.get(), 1);
+    }
+
+    struct Window {
+        buffer: [u8; 16],
+        len: usize,
+        start: *const u8,
+    }
+
+    #[test]
+    fn test_init_earlier_fields() {
+        let init = crate::init!(Window {
+            buffer: [1; 16],
+            len: buffer.len() / 2,
+            start: buffer[*len..].as_ptr(),
+        });
+        crate::slot!(slot: Window);
+        let window = slot.init(init);
+
+        assert_eq!(window.len, 8);
+        assert_eq!(window.start, window.buffer[8..].as_ptr());
+    }
+
+    struct Wrapper(u8, (u16, [u32; 2]));
+
+    #[test]
+    fn test_project_pin_tuple() {
+        crate::slot_pin!(slot: Wrapper);
+        let mut uninit = slot;
+        crate::project_pin!(Wrapper, uninit, 0).write(1);
+        crate::project_pin!(Wrapper, uninit, 1.0).write(2);
+        crate::project_pin!(Wrapper, uninit, 1.1).write([3, 4]);
+        // SAFETY: every field was initialized
+        let wrapper = unsafe { uninit.assume_init() };
+
+        assert_eq!(wrapper.0, 1);
+        assert_eq!(wrapper.1, (2, [3, 4]));
+    }
+
+    #[test]
+    fn test_project_all() {
+        let drops = &Cell::new(0);
+        crate::slot!(slot: Triple);
+        let (fields, uninit) = crate::project_all!(Triple, slot, { first, second, third });
+        core::mem::forget(fields.third.write(Counted(drops)));
+        core::mem::forget(fields.first.write(Counted(drops)));
+        core::mem::forget(fields.second.write(Counted(drops)));
+        // SAFETY: every field was initialized, and their `Init`s were forgotten
+        drop(unsafe { uninit.assume_init() });
+
+        assert_eq!(drops.get(), 3);
+    }
 }
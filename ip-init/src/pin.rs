@@ -41,7 +41,7 @@ impl<I: TryInitialize<T>, T: ?Sized + Unpin> TryPinInitialize<T> for AsPinInit<I
     fn try_pin_init(self, ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error> {
         match self.init.try_init(ptr.into_inner()) {
             Ok(init) => Ok(PinnedInit::new(init)),
-            Err(_) => todo!(),
+            Err(err) => Err(err),
         }
     }
 }
@@ -83,3 +83,18 @@ impl<I: TryPinInitialize<T>, T: ?Sized> TryPinInitialize<T> for AsInit<I, T> {
         self.init.try_pin_init(ptr)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::mem::MaybeUninit;
+
+    use crate::traits::TryInitialize;
+
+    #[test]
+    fn test_as_pin_init_error() {
+        let mut slot = MaybeUninit::<[u32; 4]>::uninit();
+        let slot = crate::PinnedUninit::from_maybe_uninit(core::pin::Pin::new(&mut slot));
+        let init = crate::slice::SliceIterInit::new(0..2).to_pin_init();
+        assert!(slot.try_init(init).is_err());
+    }
+}
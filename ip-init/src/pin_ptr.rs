@@ -15,6 +15,7 @@
 //! And stack pin initialization must yield a `Pin<&mut T>` just like normal stack pins.
 
 use crate::{
+    slice::{ExactSliceIterInit, SliceIterInitError},
     traits::{PinInitialize, TryPinInitialize},
     Init, Uninit,
 };
@@ -112,6 +113,26 @@ impl<'a, T> PinnedUninit<'a, T> {
         // SAFETY: the pointee is untouched and the pointer is kept in the pinned type-state
         unsafe { self.map_initializer(|uninit| uninit.write(value)) }
     }
+
+    /// Sets the value of the `PinnedUninit<T>` to the value returned by `f`
+    ///
+    /// This overwrites any previous value without dropping it.
+    #[inline]
+    pub fn write_with<F: FnOnce() -> T>(self, f: F) -> PinnedInit<'a, T> {
+        self.write(f())
+    }
+
+    /// Sets the value of the `PinnedUninit<T>` to the value returned by `f`,
+    /// if `f` returns an error then the `PinnedUninit<T>` is left uninitialized
+    ///
+    /// This overwrites any previous value without dropping it.
+    #[inline]
+    pub fn try_write_with<F: FnOnce() -> Result<T, E>, E>(
+        self,
+        f: F,
+    ) -> Result<PinnedInit<'a, T>, E> {
+        Ok(self.write(f()?))
+    }
 }
 
 impl<'a, T> PinnedUninit<'a, [T]> {
@@ -155,6 +176,48 @@ impl<'a, T> PinnedUninit<'a, [T]> {
         unsafe { self.map_initializer(|uninit| uninit.write_slice(slice)) }
     }
 
+    /// Initialize each element from the pin initializers (or plain values) yielded by `iter`
+    ///
+    /// The length of the iterator is checked against the length of the slice before any
+    /// element is initialized, see [`ExactSliceIterInit`](crate::slice::ExactSliceIterInit)
+    pub fn write_iter<I, E>(self, iter: I) -> Result<PinnedInit<'a, [T]>, SliceIterInitError<E>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: TryPinInitialize<T, Error = E>,
+    {
+        self.try_init(ExactSliceIterInit::new(iter.into_iter()))
+    }
+
+    /// Divides one pinned uninit slice into two at an index, borrowing from `self`
+    ///
+    /// See [`Uninit::split_at_mut`] for details
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (PinnedUninit<'_, [T]>, PinnedUninit<'_, [T]>) {
+        let len = self.len();
+        assert!(mid <= len);
+        let ptr = self.as_mut_ptr().cast::<T>();
+        // SAFETY: mid <= len, so the pointer stays in bounds of the allocation
+        let tail = unsafe { ptr.add(mid) };
+        let head = core::ptr::slice_from_raw_parts_mut(ptr, mid);
+        let tail = core::ptr::slice_from_raw_parts_mut(tail, len - mid);
+        // SAFETY: the two halves are disjoint sub-slices of this `PinnedUninit`, so they are
+        // * allocated for [T]'s layout
+        // * writable for [T]'s layout
+        // * readable for [T]'s layout after written to
+        // they are borrowed from self, so they can't outlive it
+        // and they are sub-slices of a pinned slice, so they are in the pinned type-state
+        unsafe {
+            (
+                PinnedUninit::new_unchecked(Uninit::from_raw(head)),
+                PinnedUninit::new_unchecked(Uninit::from_raw(tail)),
+            )
+        }
+    }
+
     /// Returns the length of a slice.
     ///
     /// The returned value is the number of **elements**, not the number of bytes.
@@ -180,7 +243,40 @@ impl<T: ?Sized> Deref for PinnedInit<'_, T> {
     }
 }
 
+impl<T: ?Sized> PinnedInit<'_, T> {
+    /// Get a pinned shared reference to the value
+    ///
+    /// # Safety
+    ///
+    /// `PinnedInit` doesn't guarantee that the value is dropped before it's memory is reused,
+    /// but `Pin` does. So the caller must ensure that this `PinnedInit` (or the smart pointer
+    /// it's converted into) is dropped before the memory is reused, i.e. it isn't leaked.
+    pub unsafe fn as_ref(&self) -> Pin<&T> {
+        // SAFETY: the value is in the pinned type-state, and the caller ensures
+        // that it will be dropped before it's memory is reused
+        unsafe { Pin::new_unchecked(self) }
+    }
+
+    /// Get a pinned mutable reference to the value
+    ///
+    /// # Safety
+    ///
+    /// see [`PinnedInit::as_ref`]
+    pub unsafe fn as_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: the value is in the pinned type-state, and the caller ensures
+        // that it will be dropped before it's memory is reused
+        unsafe { Pin::new_unchecked(&mut *self.as_mut_ptr()) }
+    }
+}
+
 impl<'a, T: ?Sized + Unpin> PinnedInit<'a, T> {
+    /// Get a mutable reference to the value
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: this pointer is well aligned and initalized, and uniquely borrowed
+        // `T: Unpin` so it doesn't care about pinning
+        unsafe { &mut *self.as_mut_ptr() }
+    }
+
     /// Create a new `PinnedInit`
     pub fn new(ptr: Init<'a, T>) -> Self {
         // SAFETY: `T: Unpin` so it doesn't care about pinning
@@ -0,0 +1,203 @@
+//! caller provided storage for pinned values, which guarantees that the value is dropped
+//! before the storage goes away
+//!
+//! A [`PinnedInit`](crate::PinnedInit) doesn't uphold `Pin`'s drop guarantee, because it
+//! may be leaked. A [`PinSlot`] tracks whether it holds a value, and the value is only
+//! reachable through a [`PinSlotGuard`] which drops the value when it's dropped. If the
+//! guard is leaked instead, the slot aborts the process when it's dropped (or re-initialized)
+//! rather than releasing the storage of a value which is still pinned.
+
+use core::{
+    fmt::Debug,
+    marker::PhantomPinned,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+};
+
+use crate::{traits::TryPinInitialize, PinnedUninit, Uninit};
+
+#[cold]
+#[inline(never)]
+fn abort() -> ! {
+    struct Abort;
+
+    impl Drop for Abort {
+        fn drop(&mut self) {
+            panic!("aborting because a `PinSlotGuard` was leaked")
+        }
+    }
+
+    // panicking while this is dropped during unwinding aborts the process
+    let _abort = Abort;
+    panic!("a `PinSlotGuard` was leaked while it's value is still pinned")
+}
+
+/// Storage for a pinned value, see the [module docs](self)
+pub struct PinSlot<T> {
+    value: MaybeUninit<T>,
+    initialized: bool,
+    _pin: PhantomPinned,
+}
+
+/// A guard which owns the value in a [`PinSlot`], and drops it when the guard is dropped
+pub struct PinSlotGuard<'a, T> {
+    slot: &'a mut PinSlot<T>,
+}
+
+impl<T> PinSlot<T> {
+    /// Create a new empty slot
+    pub const fn new() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+            initialized: false,
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns `true` if the slot holds a value
+    ///
+    /// This is only observable if the slot's guard was leaked
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Pin initialize a value in the slot
+    pub fn init<I>(self: Pin<&mut Self>, init: I) -> PinSlotGuard<'_, T>
+    where
+        I: TryPinInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_init(init) {
+            Ok(guard) => guard,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    /// Attempt to pin initialize a value in the slot
+    ///
+    /// If initialization fails, the slot is left empty
+    pub fn try_init<I>(self: Pin<&mut Self>, init: I) -> Result<PinSlotGuard<'_, T>, I::Error>
+    where
+        I: TryPinInitialize<T>,
+    {
+        // SAFETY: the slot is never moved out of
+        let slot = unsafe { self.get_unchecked_mut() };
+
+        if slot.initialized {
+            abort()
+        }
+
+        // SAFETY: the slot is pinned, and the value will be dropped before the slot goes away
+        // either by the guard, or by aborting in the slot's destructor
+        let uninit =
+            unsafe { PinnedUninit::new_unchecked(Uninit::from_maybe_uninit(&mut slot.value)) };
+        // ownership of the value is passed to the guard
+        uninit.try_init(init)?.into_raw();
+
+        slot.initialized = true;
+        Ok(PinSlotGuard { slot })
+    }
+}
+
+impl<T> Default for PinSlot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for PinSlot<T> {
+    fn drop(&mut self) {
+        if self.initialized {
+            abort()
+        }
+    }
+}
+
+impl<T> PinSlotGuard<'_, T> {
+    /// Get a pinned mutable reference to the value
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: the value is pinned, and the guard ensures that it will be dropped
+        // before it's memory is reused
+        unsafe { Pin::new_unchecked(self.slot.value.assume_init_mut()) }
+    }
+}
+
+impl<T> Deref for PinSlotGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the guard only exists while the value is initialized
+        unsafe { self.slot.value.assume_init_ref() }
+    }
+}
+
+impl<T: Unpin> DerefMut for PinSlotGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the guard only exists while the value is initialized
+        // and `T: Unpin` so it doesn't care about pinning
+        unsafe { self.slot.value.assume_init_mut() }
+    }
+}
+
+impl<T> Drop for PinSlotGuard<'_, T> {
+    fn drop(&mut self) {
+        self.slot.initialized = false;
+        // SAFETY: the guard only exists while the value is initialized,
+        // and the slot is now marked as empty, so it won't be used again
+        unsafe { self.slot.value.assume_init_drop() }
+    }
+}
+
+impl<T: Debug> Debug for PinSlotGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct Counted<'a> {
+        drops: &'a Cell<u32>,
+        _pin: PhantomPinned,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_pin_slot() {
+        let drops = Cell::new(0);
+        let init = || {
+            crate::func::PinInitFn::new(|uninit: PinnedUninit<Counted>| {
+                uninit.write(Counted {
+                    drops: &drops,
+                    _pin: PhantomPinned,
+                })
+            })
+        };
+
+        let mut slot = core::pin::pin!(PinSlot::<Counted>::new());
+        let mut guard = slot.as_mut().init(init());
+        let _: Pin<&mut Counted> = guard.as_mut();
+        drop(guard);
+        assert_eq!(drops.get(), 1);
+        assert!(!slot.is_initialized());
+
+        let guard = slot.as_mut().init(init());
+        assert!(guard.drops.get() == 1);
+        drop(guard);
+        assert_eq!(drops.get(), 2);
+
+        let init = crate::func::TryPinInitFn::new(|_: PinnedUninit<Counted>| Err(()));
+        assert!(slot.as_mut().try_init(init).is_err());
+        assert!(!slot.is_initialized());
+    }
+}
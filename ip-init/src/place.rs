@@ -0,0 +1,260 @@
+//! in place initialization at a caller provided address
+//!
+//! This is useful for memory which this crate doesn't own, for example memory from `mmap`,
+//! shared memory, or a static buffer. The returned [`Init`] drops the value, but never
+//! deallocates the memory.
+
+use core::{
+    alloc::{Layout, LayoutError},
+    fmt::Debug,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ptr::NonNull,
+};
+
+use crate::{
+    traits::{LayoutProvider, TryInitialize, TryPinInitialize},
+    Init, PinnedInit, PinnedUninit, Uninit,
+};
+
+/// An error type for failure to initialize a value in a [`RawPlace`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaceError<E> {
+    /// Initialization failed
+    Init(E),
+    /// The layout could not be computed
+    Layout(LayoutError),
+    /// The value is too large for the place, or the place isn't aligned for the value
+    DoesNotFit {
+        /// the layout of the value
+        required: Layout,
+        /// the layout of the place
+        available: Layout,
+    },
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for PlaceError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Init(err) => write!(f, "failed to initialize value: {err}"),
+            Self::Layout(err) => write!(f, "could not compute layout for value: {err}"),
+            Self::DoesNotFit {
+                required,
+                available,
+            } => write!(
+                f,
+                "a value with layout {required:?} doesn't fit in a place with layout {available:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for PlaceError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Init(err) => Some(err),
+            Self::Layout(err) => Some(err),
+            Self::DoesNotFit { .. } => None,
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn handle<E: Debug>(err: PlaceError<E>) -> ! {
+    match err {
+        PlaceError::Init(err) => crate::init_failed(err),
+        PlaceError::Layout(_) => panic!("Could not compute layout for value"),
+        PlaceError::DoesNotFit {
+            required,
+            available,
+        } => panic!(
+            "A value with layout {required:?} doesn't fit in a place with layout {available:?}"
+        ),
+    }
+}
+
+/// A region of memory which values can be initialized in
+///
+/// The place is borrowed while a value is initialized in it, and can be reused once that
+/// value is dropped.
+pub struct RawPlace<'a> {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    _lt: PhantomData<&'a mut [MaybeUninit<u8>]>,
+}
+
+// SAFETY: a `RawPlace` is equivalent to a `&mut [MaybeUninit<u8>]`
+unsafe impl Send for RawPlace<'_> {}
+// SAFETY: a `RawPlace` is equivalent to a `&mut [MaybeUninit<u8>]`
+unsafe impl Sync for RawPlace<'_> {}
+
+impl<'a> RawPlace<'a> {
+    /// Create a new place from a pointer and the layout of the memory it points to
+    ///
+    /// # Safety
+    ///
+    /// For the lifetime `'a` the pointer must be
+    /// * aligned to `layout.align()`
+    /// * valid for reads and writes of `layout.size()` bytes
+    /// * not accessed through any other pointer
+    pub const unsafe fn new(ptr: NonNull<u8>, layout: Layout) -> Self {
+        Self {
+            ptr,
+            layout,
+            _lt: PhantomData,
+        }
+    }
+
+    /// Create a new place from a buffer of bytes
+    pub fn from_maybe_uninit_bytes(bytes: &'a mut [MaybeUninit<u8>]) -> Self {
+        let len = bytes.len();
+        // SAFETY: a slice is never larger than `isize::MAX` bytes
+        let layout = unsafe { Layout::from_size_align_unchecked(len, 1) };
+        // SAFETY: the pointer is valid for reads and writes of `len` bytes, it's aligned to 1,
+        // and it's borrowed mutably for 'a
+        unsafe { Self::new(NonNull::from(bytes).cast(), layout) }
+    }
+
+    /// The layout of the memory which this place points to
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Acquires the underlying pointer
+    pub const fn as_ptr(&self) -> NonNull<u8> {
+        self.ptr
+    }
+
+    fn uninit<T: ?Sized, L, I, E>(
+        &mut self,
+        provider: &L,
+        init: &I,
+    ) -> Result<Uninit<'_, T>, PlaceError<E>>
+    where
+        L: LayoutProvider<T, I>,
+    {
+        let required = provider.layout_for(init).map_err(PlaceError::Layout)?;
+
+        if required.size() > self.layout.size()
+            || self.ptr.as_ptr().addr() & (required.align() - 1) != 0
+        {
+            return Err(PlaceError::DoesNotFit {
+                required,
+                available: self.layout,
+            });
+        }
+
+        let ptr = provider.cast(init, self.ptr.as_ptr());
+
+        // SAFETY: the pointer is
+        // * aligned for T's layout, this was checked above
+        // * allocated, writable, and readable for T's layout, because it fits in this place
+        // the place is borrowed mutably for as long as the `Uninit`, so it can't be aliased
+        Ok(unsafe { Uninit::from_raw(ptr) })
+    }
+
+    /// Initialize a value in place
+    pub fn emplace<T: ?Sized, L, I>(&mut self, provider: L, init: I) -> Init<'_, T>
+    where
+        I: TryInitialize<T>,
+        L: LayoutProvider<T, I>,
+        I::Error: Debug,
+    {
+        match self.try_emplace(provider, init) {
+            Ok(init) => init,
+            Err(err) => handle(err),
+        }
+    }
+
+    /// Try to initialize a value in place
+    pub fn try_emplace<T: ?Sized, L, I>(
+        &mut self,
+        provider: L,
+        init: I,
+    ) -> Result<Init<'_, T>, PlaceError<I::Error>>
+    where
+        I: TryInitialize<T>,
+        L: LayoutProvider<T, I>,
+    {
+        self.uninit(&provider, &init)?
+            .try_init(init)
+            .map_err(PlaceError::Init)
+    }
+
+    /// Pin initialize a value in place
+    pub fn emplace_pin<T: ?Sized, L, I>(&mut self, provider: L, init: I) -> PinnedInit<'_, T>
+    where
+        I: TryPinInitialize<T>,
+        L: LayoutProvider<T, I>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_pin(provider, init) {
+            Ok(init) => init,
+            Err(err) => handle(err),
+        }
+    }
+
+    /// Try to pin initialize a value in place
+    pub fn try_emplace_pin<T: ?Sized, L, I>(
+        &mut self,
+        provider: L,
+        init: I,
+    ) -> Result<PinnedInit<'_, T>, PlaceError<I::Error>>
+    where
+        I: TryPinInitialize<T>,
+        L: LayoutProvider<T, I>,
+    {
+        let uninit = self.uninit(&provider, &init)?;
+        // SAFETY: a `PinnedInit` doesn't require that the value is dropped before the memory
+        // is reused, and the place stays borrowed until the `PinnedInit` is gone
+        let uninit = unsafe { PinnedUninit::new_unchecked(uninit) };
+        init.try_pin_init(uninit).map_err(PlaceError::Init)
+    }
+}
+
+impl Debug for RawPlace<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawPlace")
+            .field("ptr", &self.ptr)
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::layout::{SizedLayoutProvider, SliceLayoutProvider};
+
+    use super::*;
+
+    #[repr(align(8))]
+    struct Buffer([MaybeUninit<u8>; 64]);
+
+    #[test]
+    fn test_place() {
+        let mut buffer = Buffer([MaybeUninit::uninit(); 64]);
+        let mut place = RawPlace::from_maybe_uninit_bytes(&mut buffer.0);
+
+        let value = place.emplace::<u64, _, _>(SizedLayoutProvider, 10);
+        assert_eq!(*value, 10);
+        drop(value);
+
+        let slice = place.emplace::<[u32], _, _>(
+            SliceLayoutProvider(16),
+            crate::slice::SliceIterInit::new(0..),
+        );
+        assert_eq!(slice[15], 15);
+        drop(slice);
+
+        let err =
+            place.try_emplace::<[u32], _, _>(SliceLayoutProvider(17), crate::zeroed::ZeroInit);
+        assert!(matches!(err, Err(PlaceError::DoesNotFit { .. })));
+        drop(err);
+
+        let mut place = RawPlace::from_maybe_uninit_bytes(&mut buffer.0[1..]);
+        let err = place.try_emplace::<u16, _, _>(SizedLayoutProvider, 0);
+        assert!(matches!(err, Err(PlaceError::DoesNotFit { .. })));
+    }
+}
@@ -0,0 +1,249 @@
+//! a fixed-capacity pool of slots which are re-initialized in place
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{traits::TryInitialize, Init, Uninit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlotState {
+    /// the slot is uninitialized
+    Free,
+    /// the slot is initialized, and checked out by a guard
+    InUse,
+    /// the slot is initialized, but not checked out
+    Cached,
+}
+
+/// A fixed-capacity pool of `N` slots for `T`
+///
+/// [`Pool::checkout`] initializes a free slot in place, so large objects can be reused
+/// without moving them or allocating. When the guard is dropped the value is dropped,
+/// or with [`PoolGuard::release_cached`] the value is kept to be checked out again
+/// with [`Pool::checkout_cached`].
+pub struct Pool<T, const N: usize> {
+    states: [Cell<SlotState>; N],
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+}
+
+/// The error for when a value can't be checked out from a [`Pool`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolError<E> {
+    /// All of the slots are in use
+    Exhausted,
+    /// Initialization failed, the slot is left free
+    Init(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for PoolError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Exhausted => f.write_str("all slots in the pool are in use"),
+            Self::Init(err) => write!(f, "failed to initialize value: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for PoolError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Exhausted => None,
+            Self::Init(err) => Some(err),
+        }
+    }
+}
+
+impl<T, const N: usize> Pool<T, N> {
+    /// Create a new pool where all slots are free
+    pub const fn new() -> Self {
+        Self {
+            states: [const { Cell::new(SlotState::Free) }; N],
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+        }
+    }
+
+    /// The number of slots in the pool
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of slots which are currently checked out
+    pub fn in_use(&self) -> usize {
+        self.states
+            .iter()
+            .filter(|state| state.get() == SlotState::InUse)
+            .count()
+    }
+
+    fn find(&self, state: SlotState) -> Option<usize> {
+        self.states.iter().position(|s| s.get() == state)
+    }
+
+    /// Check out a slot, and attempt to initialize it in place
+    ///
+    /// Free slots are used first, otherwise the value in a cached slot is dropped and the slot is reused
+    pub fn checkout<I: TryInitialize<T>>(
+        &self,
+        init: I,
+    ) -> Result<PoolGuard<'_, T, N>, PoolError<I::Error>> {
+        struct Reserved<'a>(&'a Cell<SlotState>);
+
+        impl Drop for Reserved<'_> {
+            fn drop(&mut self) {
+                self.0.set(SlotState::Free);
+            }
+        }
+
+        let (index, cached) = match self.find(SlotState::Free) {
+            Some(index) => (index, false),
+            None => (
+                self.find(SlotState::Cached).ok_or(PoolError::Exhausted)?,
+                true,
+            ),
+        };
+
+        // the slot is marked as in use while it's being initialized, so that the
+        // initializer can't check it out again. If anything fails, then the slot is freed
+        self.states[index].set(SlotState::InUse);
+        let reserved = Reserved(&self.states[index]);
+
+        let uninit = if cached {
+            // SAFETY: the slot was cached, so it's initialized and not borrowed
+            unsafe { Init::from_raw(self.slot(index)).deinit() }
+        } else {
+            // SAFETY: the slot was free, so it's uninitialized and not borrowed
+            unsafe { Uninit::from_raw(self.slot(index)) }
+        };
+
+        uninit.try_init(init).map_err(PoolError::Init)?.into_raw();
+        core::mem::forget(reserved);
+
+        Ok(PoolGuard { pool: self, index })
+    }
+
+    /// Check out a cached value without re-initializing it
+    pub fn checkout_cached(&self) -> Option<PoolGuard<'_, T, N>> {
+        let index = self.find(SlotState::Cached)?;
+        self.states[index].set(SlotState::InUse);
+        Some(PoolGuard { pool: self, index })
+    }
+
+    /// Drop all of the cached values
+    pub fn clear_cached(&mut self) {
+        for (state, slot) in self.states.iter_mut().zip(&mut self.slots) {
+            if state.get() == SlotState::Cached {
+                state.set(SlotState::Free);
+                // SAFETY: the slot was cached, so it's initialized
+                unsafe { slot.get_mut().assume_init_drop() }
+            }
+        }
+    }
+
+    fn slot(&self, index: usize) -> *mut T {
+        self.slots[index].get().cast()
+    }
+}
+
+impl<T, const N: usize> Default for Pool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for Pool<T, N> {
+    fn drop(&mut self) {
+        // no slots can be in use, since the guards borrow the pool
+        self.clear_cached()
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for Pool<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pool")
+            .field("capacity", &N)
+            .field("in_use", &self.in_use())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A value checked out of a [`Pool`], which drops the value when it's released
+pub struct PoolGuard<'a, T, const N: usize> {
+    pool: &'a Pool<T, N>,
+    index: usize,
+}
+
+impl<T, const N: usize> PoolGuard<'_, T, N> {
+    /// Release the slot back to the pool, but keep the value cached for [`Pool::checkout_cached`]
+    pub fn release_cached(self) {
+        self.pool.states[self.index].set(SlotState::Cached);
+        core::mem::forget(self);
+    }
+}
+
+impl<T, const N: usize> Deref for PoolGuard<'_, T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the slot is in use, so it's initialized and only borrowed by this guard
+        unsafe { &*self.pool.slot(self.index) }
+    }
+}
+
+impl<T, const N: usize> DerefMut for PoolGuard<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the slot is in use, so it's initialized and only borrowed by this guard
+        unsafe { &mut *self.pool.slot(self.index) }
+    }
+}
+
+impl<T, const N: usize> Drop for PoolGuard<'_, T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the slot is in use, so it's initialized and only borrowed by this guard
+        unsafe { Init::from_raw(self.pool.slot(self.index)).deinit() };
+        // the slot is only freed after the value is dropped, so that it can't be
+        // checked out while the value is being dropped
+        self.pool.states[self.index].set(SlotState::Free);
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for PoolGuard<'_, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pool() {
+        let pool = Pool::<[u8; 16], 2>::new();
+
+        let mut a = pool.checkout([1; 16]).unwrap();
+        let b = pool.checkout([2; 16]).unwrap();
+        assert_eq!(pool.in_use(), 2);
+        assert!(matches!(pool.checkout([3; 16]), Err(PoolError::Exhausted)));
+
+        a[0] = 0;
+        a.release_cached();
+        drop(b);
+        assert_eq!(pool.in_use(), 0);
+
+        let a = pool.checkout_cached().unwrap();
+        assert_eq!(a[..2], [0, 1]);
+        assert!(pool.checkout_cached().is_none());
+        a.release_cached();
+
+        let err = pool.checkout(crate::slice::SliceIterInit::new(0..1));
+        assert!(matches!(err, Err(PoolError::Init(_))));
+
+        let b = pool.checkout([4; 16]).unwrap();
+        let c = pool.checkout([5; 16]).unwrap();
+        assert_eq!((*b, *c), ([4; 16], [5; 16]));
+    }
+}
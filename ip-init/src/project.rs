@@ -0,0 +1,264 @@
+//! typed projections from uninitialized structs to their fields
+//!
+//! This is a compile-checked alternative to [`project!`](crate::project) and
+//! [`project_pin!`](crate::project_pin), which can be derived with the `derive` feature.
+//! Fields marked with `#[pin]` are structurally pinned, so they are projected from a
+//! [`PinnedUninit`] to a [`PinnedUninit`], and all other fields are projected to an [`Uninit`].
+//!
+//! To keep structural pinning sound, the derive rejects `#[repr(packed)]` structs, and
+//! the struct must not implement `Drop` or `Unpin` (it is `Unpin` exactly when all of it's
+//! `#[pin]` fields are).
+//!
+//! To run a destructor which receives `Pin<&mut Self>`, for example to unlink an intrusive
+//! node, add `#[project_uninit(PinnedDrop)]` and implement [`PinnedDrop`] instead of `Drop`.
+//!
+//! The derive can be combined with `pin_project`, so the same `#[pin]` attributes control
+//! structural pinning both during and after initialization. Place the derive before the
+//! `#[pin_project]` attribute and add `#[project_uninit(unsafe_pin_project)]`, then
+//! `pin_project` provides the `Drop` and `Unpin` checks instead of the derive. This is
+//! unsound if the struct isn't actually annotated with `pin_project`'s `#[pin_project]`.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use core::marker::PhantomPinned;
+//! use ip_init::{project::ProjectUninit, PinnedInit, PinnedUninit};
+//!
+//! #[derive(ProjectUninit)]
+//! struct Timer {
+//!     #[pin]
+//!     node: PhantomPinned,
+//!     deadline: u64,
+//! }
+//!
+//! fn init(mut uninit: PinnedUninit<Timer>) -> PinnedInit<Timer> {
+//!     let fields = uninit.project();
+//!     let _: PinnedUninit<PhantomPinned> = fields.node;
+//!     fields.deadline.write(10);
+//!
+//!     uninit.write(Timer {
+//!         node: PhantomPinned,
+//!         deadline: 10,
+//!     })
+//! }
+//! # }
+//! ```
+
+use core::pin::Pin;
+
+use crate::{Init, PinnedUninit, Uninit};
+
+#[cfg(feature = "derive")]
+pub use ip_init_derive::ProjectUninit;
+
+/// A struct which can be projected from an uninitialized pointer to pointers to it's fields
+///
+/// # Safety
+///
+/// * each field of the projections must point to the corresponding field of the struct
+/// * a field may only be projected to a [`PinnedUninit`] if it's structurally pinned
+pub unsafe trait ProjectUninit {
+    /// The projection of an [`Uninit<Self>`], which has an [`Uninit`] for each field
+    type Projection<'a>
+    where
+        Self: 'a;
+
+    /// The projection of a [`PinnedUninit<Self>`], which has a [`PinnedUninit`]
+    /// for each structurally pinned field, and an [`Uninit`] for each other field
+    type PinnedProjection<'a>
+    where
+        Self: 'a;
+
+    /// Project an [`Uninit<Self>`] to it's fields
+    fn project_uninit<'a>(uninit: &'a mut Uninit<'_, Self>) -> Self::Projection<'a>;
+
+    /// Project a [`PinnedUninit<Self>`] to it's fields
+    fn project_pinned_uninit<'a>(
+        uninit: &'a mut PinnedUninit<'_, Self>,
+    ) -> Self::PinnedProjection<'a>;
+}
+
+/// An uninit pointer which was split into pointers to it's fields by
+/// [`project_all!`](crate::project_all)
+///
+/// It can't be projected again, so it can only be turned back into an [`Init`]
+/// once every field is initialized.
+pub struct ProjectedUninit<'a, T: ?Sized>(Uninit<'a, T>);
+
+impl<'a, T: ?Sized> ProjectedUninit<'a, T> {
+    #[doc(hidden)]
+    pub fn new(uninit: Uninit<'a, T>) -> Self {
+        Self(uninit)
+    }
+
+    /// Assert that every field was initialized
+    ///
+    /// # Safety
+    ///
+    /// Every field must be initialized, and the [`Init`]s of the fields must be forgotten
+    pub unsafe fn assume_init(self) -> Init<'a, T> {
+        // SAFETY: the caller ensures that every field is initialized
+        unsafe { self.0.assume_init() }
+    }
+}
+
+/// A destructor which receives `Pin<&mut Self>`, so it may rely on `Self` being pinned
+///
+/// `#[derive(ProjectUninit)]` with `#[project_uninit(PinnedDrop)]` implements `Drop` in terms
+/// of this trait.
+pub trait PinnedDrop {
+    /// Run the destructor, see [`Drop::drop`]
+    fn drop(self: Pin<&mut Self>, token: OnlyCallFromDrop);
+}
+
+/// A token which ensures that [`PinnedDrop::drop`] is only called from `Drop::drop`
+pub struct OnlyCallFromDrop(());
+
+impl OnlyCallFromDrop {
+    /// Create a new token
+    ///
+    /// # Safety
+    ///
+    /// This must only be used to call [`PinnedDrop::drop`] from `Drop::drop`
+    #[doc(hidden)]
+    pub unsafe fn new() -> Self {
+        Self(())
+    }
+}
+
+impl<T: ?Sized + ProjectUninit> Uninit<'_, T> {
+    /// Project this pointer to pointers to each of the fields of `T`
+    pub fn project(&mut self) -> T::Projection<'_> {
+        T::project_uninit(self)
+    }
+}
+
+impl<T: ?Sized + ProjectUninit> PinnedUninit<'_, T> {
+    /// Project this pointer to pointers to each of the fields of `T`
+    pub fn project(&mut self) -> T::PinnedProjection<'_> {
+        T::project_pinned_uninit(self)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod test {
+    use core::{marker::PhantomPinned, mem::MaybeUninit};
+
+    use super::*;
+
+    #[derive(ProjectUninit)]
+    struct Named<T> {
+        #[pin]
+        pinned: T,
+        value: u32,
+    }
+
+    #[derive(ProjectUninit)]
+    struct Tuple<'a>(&'a str, #[pin] PhantomPinned);
+
+    #[derive(ProjectUninit)]
+    struct Unit;
+
+    #[derive(ProjectUninit)]
+    #[project_uninit(PinnedDrop)]
+    struct Node<'a> {
+        #[pin]
+        _pin: PhantomPinned,
+        drops: &'a core::cell::Cell<u32>,
+    }
+
+    impl PinnedDrop for Node<'_> {
+        fn drop(self: Pin<&mut Self>, _: OnlyCallFromDrop) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_project() {
+        let mut slot = MaybeUninit::<Named<[u8; 4]>>::uninit();
+        let mut uninit = Uninit::from_maybe_uninit(&mut slot);
+        let fields = uninit.project();
+        fields.pinned.write([1; 4]);
+        fields.value.write(2);
+        // SAFETY: all fields were initialized
+        let named = unsafe { uninit.assume_init() };
+        assert_eq!(named.pinned, [1; 4]);
+        assert_eq!(named.value, 2);
+
+        let slot = core::pin::pin!(MaybeUninit::<Tuple>::uninit());
+        let mut uninit = PinnedUninit::from_maybe_uninit(slot);
+        let fields = uninit.project();
+        let _: PinnedUninit<PhantomPinned> = fields.1;
+        fields.0.write("hi");
+
+        let mut slot = MaybeUninit::<Unit>::uninit();
+        Uninit::from_maybe_uninit(&mut slot).project();
+    }
+
+    #[derive(crate::Init)]
+    struct Pair<T> {
+        first: T,
+        second_value: [u32; 8],
+    }
+
+    #[test]
+    fn test_derive_init() {
+        let mut slot = MaybeUninit::uninit();
+        let init = PairInit {
+            first: ::alloc::rc::Rc::new(()),
+            second_value: crate::array::ArrayFromFn::new(|i| i as u32),
+        };
+        let pair: crate::Init<Pair<_>> =
+            Uninit::from_maybe_uninit(&mut slot).try_init(init).unwrap();
+        assert_eq!(pair.second_value[7], 7);
+
+        let first = ::alloc::rc::Rc::new(());
+        let init = PairInit {
+            first: first.clone(),
+            second_value: crate::slice::SliceIterInit::new(0..1),
+        };
+        let mut slot = MaybeUninit::<Pair<_>>::uninit();
+        let err = Uninit::from_maybe_uninit(&mut slot)
+            .try_init(init)
+            .map(drop);
+        assert!(matches!(err, Err(PairInitError::SecondValue(_))));
+        assert_eq!(::alloc::rc::Rc::strong_count(&first), 1);
+    }
+
+    #[test]
+    fn test_pinned_drop() {
+        let drops = core::cell::Cell::new(0);
+        drop(Node {
+            _pin: PhantomPinned,
+            drops: &drops,
+        });
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_pin_init() {
+        let drops = &core::cell::Cell::new(0);
+        {
+            let init = crate::pin_init!(Node {
+                _pin <- crate::func::PinInitFn::new(|uninit: PinnedUninit<_>| {
+                    uninit.write(PhantomPinned)
+                }),
+                drops,
+            });
+            crate::stack_emplace!(node: Node = init);
+            assert_eq!(node.drops.get(), 0);
+        }
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn test_try_pin_init() {
+        let drops = &core::cell::Cell::new(0);
+        let init = crate::try_pin_init!(Node {
+            _pin: PhantomPinned,
+            drops: if drops.get() == 0 { Err(())? } else { drops },
+        }?());
+        crate::slot_pin!(slot: Node);
+        assert!(slot.try_init(init).is_err());
+        assert_eq!(drops.get(), 0);
+    }
+}
@@ -70,6 +70,34 @@ impl<'a, T> Uninit<'a, T> {
         // SAFETY: the slice was initialized by the write above
         unsafe { self.assume_init() }
     }
+
+    /// Sets the value of the `Uninit<T>` to the value returned by `f`
+    ///
+    /// This overwrites any previous value without dropping it.
+    #[inline]
+    pub fn write_with<F: FnOnce() -> T>(self, f: F) -> Init<'a, T> {
+        self.write(f())
+    }
+
+    /// Sets the value of the `Uninit<T>` to the value returned by `f`,
+    /// if `f` returns an error then the `Uninit<T>` is left uninitialized
+    ///
+    /// This overwrites any previous value without dropping it.
+    #[inline]
+    pub fn try_write_with<F: FnOnce() -> Result<T, E>, E>(self, f: F) -> Result<Init<'a, T>, E> {
+        Ok(self.write(f()?))
+    }
+}
+
+impl<'a, T> Init<'a, T> {
+    /// Move the value out of this `Init<T>`
+    ///
+    /// The slot is left logically uninitialized, so it won't be dropped again
+    pub fn into_inner(self) -> T {
+        // SAFETY: the pointee is a valid instance of T, and `into_raw` ensures
+        // that it is never dropped in place after this read
+        unsafe { self.into_raw().as_ptr().read() }
+    }
 }
 
 impl<'a, T, const N: usize> Uninit<'a, [T; N]> {
@@ -138,6 +166,30 @@ impl<'a, T> Uninit<'a, [T]> {
         unsafe { self.assume_init() }
     }
 
+    /// Divides one uninit slice into two at an index, borrowing from `self`
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid` itself)
+    /// and the second will contain all indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (Uninit<'_, [T]>, Uninit<'_, [T]>) {
+        let len = self.len();
+        assert!(mid <= len);
+        let ptr = self.as_mut_ptr().cast::<T>();
+        // SAFETY: mid <= len, so the pointer stays in bounds of the allocation
+        let tail = unsafe { ptr.add(mid) };
+        let head = ptr::slice_from_raw_parts_mut(ptr, mid);
+        let tail = ptr::slice_from_raw_parts_mut(tail, len - mid);
+        // SAFETY: the two halves are disjoint sub-slices of this `Uninit`, so they are
+        // * allocated for [T]'s layout
+        // * writable for [T]'s layout
+        // * readable for [T]'s layout after written to
+        // and they are borrowed from self, so they can't outlive it
+        unsafe { (Uninit::from_raw(head), Uninit::from_raw(tail)) }
+    }
+
     /// Returns the length of a slice.
     ///
     /// The returned value is the number of **elements**, not the number of bytes.
@@ -153,6 +205,116 @@ impl<'a, T> Uninit<'a, [T]> {
     }
 }
 
+impl<'a, T, const N: usize> Uninit<'a, [[T; N]]> {
+    /// View this slice of rows as a single contiguous slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the resulting slice would overflow a `usize`,
+    /// this is only possible for zero-sized `T`
+    pub fn into_flattened(self) -> Uninit<'a, [T]> {
+        let len = self.len().checked_mul(N).expect("slice len overflow");
+        let ptr = ptr::slice_from_raw_parts_mut(self.as_non_null_ptr().as_ptr().cast::<T>(), len);
+        // SAFETY: `[[T; N]]` has the same layout as `[T]` with `N` times the length
+        // and the pointer comes from an `Uninit`, so it is
+        // * allocated for [T]'s layout
+        // * writable for [T]'s layout
+        // * readable for [T]'s layout after written to
+        unsafe { Uninit::from_raw(ptr) }
+    }
+}
+
+impl<'a, T> Uninit<'a, [T]> {
+    /// View this slice as a row-major matrix with `N` columns, so that each row can be
+    /// initialized as a `[T; N]`
+    ///
+    /// If the length of this slice isn't a multiple of `N` (or `N == 0`), then `self` is returned
+    pub fn into_rows<const N: usize>(self) -> Result<Uninit<'a, [[T; N]]>, Self> {
+        if N == 0 || !self.len().is_multiple_of(N) {
+            return Err(self);
+        }
+        let ptr = ptr::slice_from_raw_parts_mut(
+            self.as_non_null_ptr().as_ptr().cast::<[T; N]>(),
+            self.len() / N,
+        );
+        // SAFETY: `[T]` has the same layout as `[[T; N]]` with a `N`th of the length,
+        // and the pointer comes from an `Uninit`, so it is
+        // * allocated for [[T; N]]'s layout
+        // * writable for [[T; N]]'s layout
+        // * readable for [[T; N]]'s layout after written to
+        Ok(unsafe { Uninit::from_raw(ptr) })
+    }
+}
+
+impl<'a, T, const N: usize> Init<'a, [[T; N]]> {
+    /// View this slice of rows as a single contiguous slice
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the resulting slice would overflow a `usize`,
+    /// this is only possible for zero-sized `T`
+    pub fn into_flattened(self) -> Init<'a, [T]> {
+        let len = self.len().checked_mul(N).expect("slice len overflow");
+        let ptr = ptr::slice_from_raw_parts_mut(self.into_raw().as_ptr().cast::<T>(), len);
+        // SAFETY: `[[T; N]]` has the same layout as `[T]` with `N` times the length
+        // and the pointer comes from an `Init`, so the pointee is a valid `[T]`
+        unsafe { Init::from_raw(ptr) }
+    }
+}
+
+impl<'a, T> Init<'a, [T]> {
+    /// View this slice as a row-major matrix with `N` columns
+    ///
+    /// If the length of this slice isn't a multiple of `N` (or `N == 0`), then `self` is returned
+    pub fn into_rows<const N: usize>(self) -> Result<Init<'a, [[T; N]]>, Self> {
+        if N == 0 || !self.len().is_multiple_of(N) {
+            return Err(self);
+        }
+        let len = self.len() / N;
+        let ptr = ptr::slice_from_raw_parts_mut(self.into_raw().as_ptr().cast::<[T; N]>(), len);
+        // SAFETY: `[T]` has the same layout as `[[T; N]]` with a `N`th of the length,
+        // and the pointer comes from an `Init`, so the pointee is a valid `[[T; N]]`
+        Ok(unsafe { Init::from_raw(ptr) })
+    }
+}
+
+impl<'a> Uninit<'a, str> {
+    /// View the string slot as a byte slot
+    ///
+    /// Initializing the bytes doesn't initialize the string, that must be done with
+    /// [`Uninit::assume_init`] after checking that the bytes are valid UTF-8
+    pub fn into_bytes(self) -> Uninit<'a, [u8]> {
+        // SAFETY: `str` has the same layout as `[u8]`
+        unsafe { Uninit::from_raw(self.as_non_null_ptr().as_ptr() as *mut [u8]) }
+    }
+
+    /// Sets the value of the `Uninit<str>`
+    ///
+    /// This also returns a `Init<'_, str>` to the now safely initialized
+    /// contents of self.
+    ///
+    /// # Panics
+    ///
+    /// If the length of this string is not equal to the length of `value`, this method panics
+    pub fn write_str(self, value: &str) -> Init<'a, str> {
+        let bytes = self.into_bytes().write_slice(value.as_bytes());
+        // SAFETY: the bytes were copied from a `str` so they are valid UTF-8
+        unsafe { Init::from_raw(bytes.into_raw().as_ptr() as *mut str) }
+    }
+
+    /// Returns the length of the string in bytes
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        (self.as_ptr() as *const [u8]).len()
+    }
+
+    /// Returns `true` if the string has a length of 0.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl<T: ?Sized> core::ops::Deref for Init<'_, T> {
     type Target = T;
 
@@ -9,6 +9,11 @@ pub struct Uninit<'a, T: ?Sized> {
     lt: PhantomData<(&'a T, Invariant<'a>)>,
 }
 
+// SAFETY: The Uninit is a unique pointer to a slot for T, like `&mut MaybeUninit<T>`
+unsafe impl<'a, T: ?Sized + Send> Send for Uninit<'a, T> {}
+// SAFETY: The Uninit doesn't allow any access to the slot by &Uninit<T>
+unsafe impl<'a, T: ?Sized + Sync> Sync for Uninit<'a, T> {}
+
 impl<'a, T: ?Sized> Uninit<'a, T> {
     /// Create a pointer to uninitialized memory
     ///
@@ -84,9 +89,9 @@ pub struct Init<'a, T: ?Sized> {
 }
 
 // SAFETY: The Init contains a T and allows accessing a T by &mut T
-unsafe impl<'a, T: Send> Send for Init<'a, T> {}
+unsafe impl<'a, T: ?Sized + Send> Send for Init<'a, T> {}
 // SAFETY: The Init contains a T and allows accessing a T by &T
-unsafe impl<'a, T: Sync> Sync for Init<'a, T> {}
+unsafe impl<'a, T: ?Sized + Sync> Sync for Init<'a, T> {}
 
 impl<'a, T: ?Sized> Init<'a, T> {
     /// Create a pointer to initialized memory
@@ -152,6 +157,26 @@ impl<'a, T: ?Sized> Init<'a, T> {
     pub fn as_non_null_ptr(&self) -> NonNull<T> {
         self.ptr
     }
+
+    /// Drop the value in place, and get back the uninitialized slot so that it can be reused
+    #[inline]
+    pub fn deinit(self) -> Uninit<'a, T> {
+        let ptr = self.into_raw();
+        // SAFETY: the pointee is a valid instance of T, and it isn't used after being dropped.
+        // If dropping panics, then the slot is leaked which is safe
+        unsafe { ptr.as_ptr().drop_in_place() }
+        // SAFETY: the pointer came from an `Init`, so it is allocated and
+        // valid for reads and writes for `'a`
+        unsafe { Uninit::from_raw_nonnull(ptr) }
+    }
+
+    /// Leak the value, so that it's never dropped, and get a reference to it
+    #[inline(always)]
+    pub fn leak(self) -> &'a mut T {
+        // SAFETY: the pointee is a valid instance of T, which is borrowed for `'a`
+        // and it is never dropped, since `into_raw` doesn't drop self
+        unsafe { &mut *self.into_raw().as_ptr() }
+    }
 }
 
 // SAFETY: the drop impl only drops the T, so is trivially correct for #[may_dangle]
@@ -0,0 +1,250 @@
+//! create and initialize reference counted allocations in place
+//!
+//! The value is constructed directly in the `Rc` allocation, after the reference counts,
+//! instead of being moved into it.
+
+use core::{fmt::Debug, pin::Pin};
+
+use ::alloc::rc::Rc;
+
+use crate::traits::{TryInitialize, TryPinInitialize};
+
+/// create a new T, and initialize it in place
+pub fn emplace<T, I>(init: I) -> Rc<T>
+where
+    I: TryInitialize<T>,
+    I::Error: Debug,
+{
+    match try_emplace(init) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new T, and pin initialize it in place
+pub fn emplace_pin<T, I>(init: I) -> Pin<Rc<T>>
+where
+    I: TryPinInitialize<T>,
+    I::Error: Debug,
+{
+    match try_emplace_pin(init) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new T, and attempt to initialize it in place
+///
+/// If initialization fails, the allocation is freed and the error is returned
+pub fn try_emplace<T, I>(init: I) -> Result<Rc<T>, I::Error>
+where
+    I: TryInitialize<T>,
+{
+    let mut rc = Rc::<T>::new_uninit();
+    let slot = unique_slot(&mut rc);
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    unsafe { crate::raw::try_init_in_place(init, slot.as_mut_ptr())? }
+
+    // SAFETY: the value was just initialized
+    Ok(unsafe { rc.assume_init() })
+}
+
+/// create a new T, and attempt to pin initialize it in place
+///
+/// If initialization fails, the allocation is freed and the error is returned
+///
+/// The value is never moved out of the allocation, and it's dropped in place when the last
+/// `Rc` (including weak pointers, which keep the allocation alive) is gone, before the
+/// allocation is freed. So this upholds `Pin`'s drop guarantee.
+pub fn try_emplace_pin<T, I>(init: I) -> Result<Pin<Rc<T>>, I::Error>
+where
+    I: TryPinInitialize<T>,
+{
+    let mut rc = Rc::<T>::new_uninit();
+    let slot = unique_slot(&mut rc);
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    // and the value is never moved out of the allocation
+    unsafe { crate::raw::try_pin_init_in_place(init, slot.as_mut_ptr())? }
+
+    // SAFETY: the value was just initialized, and `Rc` never gives out
+    // mutable access to a shared value, so it can't be moved
+    Ok(unsafe { Pin::new_unchecked(rc.assume_init()) })
+}
+
+/// create a new slice of length `len`, and initialize it in place
+pub fn emplace_slice<T, I>(len: usize, init: I) -> Rc<[T]>
+where
+    I: TryInitialize<[T]>,
+    I::Error: Debug,
+{
+    match try_emplace_slice(len, init) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new slice of length `len`, and attempt to initialize it in place
+///
+/// The elements are initialized in the final allocation. If initialization fails,
+/// the elements which were already initialized are dropped by the initializer,
+/// the allocation is freed, and the error is returned
+pub fn try_emplace_slice<T, I>(len: usize, init: I) -> Result<Rc<[T]>, I::Error>
+where
+    I: TryInitialize<[T]>,
+{
+    let mut rc = Rc::<[T]>::new_uninit_slice(len);
+    let slot = unique_slot(&mut rc);
+
+    let init = crate::Uninit::from_maybe_uninit_slice(slot).try_init(init)?;
+    // ownership of the elements is passed to the `Rc`
+    core::mem::forget(init);
+
+    // SAFETY: all elements were just initialized
+    Ok(unsafe { rc.assume_init() })
+}
+
+/// create a new slice of length `len`, and pin initialize it in place
+pub fn emplace_slice_pin<T, I>(len: usize, init: I) -> Pin<Rc<[T]>>
+where
+    I: TryPinInitialize<[T]>,
+    I::Error: Debug,
+{
+    match try_emplace_slice_pin(len, init) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new slice of length `len`, and attempt to pin initialize it in place
+///
+/// See [`try_emplace_pin`] for the pinning guarantees, and [`try_emplace_slice`] for
+/// what happens on failure
+pub fn try_emplace_slice_pin<T, I>(len: usize, init: I) -> Result<Pin<Rc<[T]>>, I::Error>
+where
+    I: TryPinInitialize<[T]>,
+{
+    let mut rc = Rc::<[T]>::new_uninit_slice(len);
+    let slot = unique_slot(&mut rc) as *mut [core::mem::MaybeUninit<T>] as *mut [T];
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    // and the elements are never moved out of the allocation
+    unsafe { crate::raw::try_pin_init_in_place(init, slot)? }
+
+    // SAFETY: all elements were just initialized, and `Rc` never gives out
+    // mutable access to a shared value, so they can't be moved
+    Ok(unsafe { Pin::new_unchecked(rc.assume_init()) })
+}
+
+/// create a new string which copies `value`, without an intermediate `String`
+pub fn emplace_str(value: &str) -> Rc<str> {
+    match try_emplace_str(value.len(), crate::str::StrInit::new(value)) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new string of `len` bytes from the given chars
+///
+/// # Panics
+///
+/// If the chars don't encode to exactly `len` bytes
+pub fn emplace_str_chars<C: IntoIterator<Item = char>>(len: usize, chars: C) -> Rc<str> {
+    match try_emplace_str(len, crate::str::CharsInit::new(chars)) {
+        Ok(rc) => rc,
+        Err(err) => crate::init_failed(err),
+    }
+}
+
+/// create a new string of `len` bytes, and attempt to initialize it in place
+pub fn try_emplace_str<I>(len: usize, init: I) -> Result<Rc<str>, I::Error>
+where
+    I: TryInitialize<str>,
+{
+    let mut rc = Rc::<[u8]>::new_uninit_slice(len);
+    let slot = unique_slot(&mut rc) as *mut [core::mem::MaybeUninit<u8>] as *mut str;
+
+    // SAFETY: the slot is uniquely borrowed, so it is valid for writes and reads (after writes)
+    unsafe { crate::raw::try_init_in_place(init, slot)? }
+
+    // SAFETY: the bytes were just initialized with a valid string
+    // and `str` has the same layout as `[u8]`
+    Ok(unsafe { Rc::from_raw(Rc::into_raw(rc.assume_init()) as *const str) })
+}
+
+fn unique_slot<T: ?Sized>(rc: &mut Rc<T>) -> &mut T {
+    match Rc::get_mut(rc) {
+        Some(slot) => slot,
+        None => unreachable!("A new `Rc` is always unique"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_emplace() {
+        let rc = emplace::<[u32; 4], _>(crate::array::ArrayFromFn::new(|i| i as u32));
+        assert_eq!(*rc, [0, 1, 2, 3]);
+
+        let rc = emplace_pin::<[u32; 2], _>([1; 2]);
+        assert_eq!(*rc, [1; 2]);
+
+        let err = try_emplace::<[u32; 4], _>(crate::slice::SliceIterInit::new(0..2));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_slice() {
+        let rc = emplace_slice(3, crate::slice::SliceIterInit::new(0..));
+        assert_eq!(*rc, [0, 1, 2]);
+
+        let err = try_emplace_slice::<u32, _>(4, crate::slice::SliceIterInit::new(0..2));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_pin() {
+        use core::{cell::Cell, marker::PhantomPinned};
+
+        struct Node<'a> {
+            drops: &'a Cell<u32>,
+            _pin: PhantomPinned,
+        }
+
+        impl Drop for Node<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let node = || {
+            crate::func::PinInitFn::new(|uninit: crate::PinnedUninit<Node>| {
+                uninit.write(Node {
+                    drops: &drops,
+                    _pin: PhantomPinned,
+                })
+            })
+        };
+
+        let rc = emplace_pin::<Node, _>(node());
+        let shared = rc.clone();
+        drop(rc);
+        assert_eq!(drops.get(), 0);
+        drop(shared);
+        assert_eq!(drops.get(), 1);
+
+        let rc = emplace_slice_pin::<u32, _>(3, crate::slice::SliceIterInit::new(0..));
+        assert_eq!(*rc, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_emplace_str() {
+        assert_eq!(&*emplace_str("hello"), "hello");
+        assert_eq!(&*emplace_str_chars(3, "abc".chars()), "abc");
+        assert!(try_emplace_str(2, crate::str::CharsInit::new("abc".chars())).is_err());
+    }
+}
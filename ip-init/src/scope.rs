@@ -0,0 +1,86 @@
+//! scoped helpers which initialize a value on the stack, and drop it once it's no longer needed
+//!
+//! These manage the stack slot, initialization, and drop internally,
+//! so they don't require touching [`Uninit`] at all.
+
+use core::{mem::MaybeUninit, pin::Pin};
+
+use crate::{
+    traits::{TryInitialize, TryPinInitialize},
+    Uninit,
+};
+
+/// Initialize a value on the stack, and pass it to `f`
+///
+/// The value is dropped after `f` returns (or panics)
+pub fn with_emplaced<T, I, F, R>(init: I, f: F) -> Result<R, I::Error>
+where
+    I: TryInitialize<T>,
+    F: FnOnce(&mut T) -> R,
+{
+    let mut slot = MaybeUninit::uninit();
+    let mut value = Uninit::from_maybe_uninit(&mut slot).try_init(init)?;
+    Ok(f(&mut value))
+}
+
+/// Pin initialize a value on the stack, and pass it to `f`
+///
+/// The value is dropped after `f` returns (or panics), before it's storage is released
+pub fn with_emplaced_pin<T, I, F, R>(init: I, f: F) -> Result<R, I::Error>
+where
+    I: TryPinInitialize<T>,
+    F: FnOnce(Pin<&mut T>) -> R,
+{
+    crate::stack_emplace!(try value: T = init);
+    Ok(f(value))
+}
+
+#[cfg(test)]
+mod test {
+    use core::{cell::Cell, marker::PhantomPinned};
+
+    use super::*;
+
+    struct Pinned<'a> {
+        drops: &'a Cell<u32>,
+        _pin: PhantomPinned,
+    }
+
+    impl Drop for Pinned<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_with_emplaced() {
+        let sum = with_emplaced::<[u32; 256], _, _, _>(
+            crate::array::ArrayFromFn::new(|i| i as u32),
+            |array| {
+                array[0] = 100;
+                array.iter().sum::<u32>()
+            },
+        );
+        assert_eq!(sum, Ok(100 + 255 * 256 / 2));
+
+        let err = with_emplaced::<[u32; 4], _, _, _>(
+            crate::slice::SliceIterInit::new(0..1),
+            |_| unreachable!(),
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_with_emplaced_pin() {
+        let drops = Cell::new(0);
+        let init = crate::func::PinInitFn::new(|uninit: crate::PinnedUninit<Pinned>| {
+            uninit.write(Pinned {
+                drops: &drops,
+                _pin: PhantomPinned,
+            })
+        });
+        let result = with_emplaced_pin(init, |value: Pin<&mut Pinned>| value.drops.get());
+        assert_eq!(result, Ok(0));
+        assert_eq!(drops.get(), 1);
+    }
+}
@@ -0,0 +1,336 @@
+//! index-addressed storage which initializes values in place in reused slots
+//!
+//! Each slot has a generation which is bumped when it's value is removed, so a stale
+//! [`Key`] never refers to a value which was inserted later in the same slot. A slot
+//! whose generation would wrap around is retired, and never reused.
+
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use ::alloc::vec::Vec;
+
+use crate::{traits::TryInitialize, Uninit};
+
+const NO_FREE_SLOT: usize = usize::MAX;
+
+/// A key which refers to a value in a [`Slab`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    index: usize,
+    generation: u32,
+}
+
+impl Key {
+    /// The index of the slot this key refers to
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The generation of the slot this key refers to
+    pub const fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+struct Slot<T> {
+    generation: u32,
+    occupied: bool,
+    next_free: usize,
+    value: MaybeUninit<T>,
+}
+
+/// Index-addressed storage, where values are initialized in place in a free slot
+///
+/// Removed slots are reused by later inserts, so the slab only grows when all slots are occupied.
+pub struct Slab<T> {
+    slots: Vec<Slot<T>>,
+    free: usize,
+    len: usize,
+}
+
+impl<T> Slab<T> {
+    /// Create a new empty slab, this doesn't allocate
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: NO_FREE_SLOT,
+            len: 0,
+        }
+    }
+
+    /// Create a new empty slab with room for at least `capacity` values
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: NO_FREE_SLOT,
+            len: 0,
+        }
+    }
+
+    /// The number of values in the slab
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slab has no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of values the slab can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Initialize a value in place in a free slot, and return it's key
+    pub fn insert_with<I>(&mut self, init: I) -> Key
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_insert_with(init) {
+            Ok(key) => key,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    /// Attempt to initialize a value in place in a free slot, and return it's key
+    ///
+    /// If initialization fails, the slot is left free
+    pub fn try_insert_with<I>(&mut self, init: I) -> Result<Key, I::Error>
+    where
+        I: TryInitialize<T>,
+    {
+        if self.free == NO_FREE_SLOT {
+            self.free = self.slots.len();
+            self.slots.push(Slot {
+                generation: 0,
+                occupied: false,
+                next_free: NO_FREE_SLOT,
+                value: MaybeUninit::uninit(),
+            });
+        }
+
+        let index = self.free;
+        let slot = &mut self.slots[index];
+
+        // ownership of the value is passed to the slot
+        Uninit::from_maybe_uninit(&mut slot.value)
+            .try_init(init)?
+            .into_raw();
+
+        slot.occupied = true;
+        self.free = slot.next_free;
+        self.len += 1;
+
+        Ok(Key {
+            index,
+            generation: slot.generation,
+        })
+    }
+
+    fn slot(&self, key: Key) -> Option<&Slot<T>> {
+        self.slots
+            .get(key.index)
+            .filter(|slot| slot.occupied && slot.generation == key.generation)
+    }
+
+    fn slot_mut(&mut self, key: Key) -> Option<&mut Slot<T>> {
+        self.slots
+            .get_mut(key.index)
+            .filter(|slot| slot.occupied && slot.generation == key.generation)
+    }
+
+    /// Returns `true` if the key refers to a value in the slab
+    pub fn contains(&self, key: Key) -> bool {
+        self.slot(key).is_some()
+    }
+
+    /// Get a reference to the value the key refers to
+    pub fn get(&self, key: Key) -> Option<&T> {
+        // SAFETY: the slot is occupied, so the value is initialized
+        self.slot(key)
+            .map(|slot| unsafe { slot.value.assume_init_ref() })
+    }
+
+    /// Get a mutable reference to the value the key refers to
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        // SAFETY: the slot is occupied, so the value is initialized
+        self.slot_mut(key)
+            .map(|slot| unsafe { slot.value.assume_init_mut() })
+    }
+
+    /// Mark the slot as free, and return a pointer to it's value
+    /// which the caller now owns
+    fn vacate(&mut self, key: Key) -> Option<*mut T> {
+        let free = self.free;
+        let slot = self.slot_mut(key)?;
+
+        slot.occupied = false;
+        let value = slot.value.as_mut_ptr();
+
+        // if the generation would wrap around, then the slot is retired instead of reused,
+        // because stale keys from the first generation would refer to it's new values
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            slot.next_free = free;
+            self.free = key.index;
+        }
+
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Drop the value the key refers to in place, and free it's slot
+    ///
+    /// Returns `false` if the key doesn't refer to a value in the slab
+    pub fn remove(&mut self, key: Key) -> bool {
+        match self.vacate(key) {
+            // SAFETY: the slot was occupied, so the value is initialized
+            // and the slot is now free, so the value won't be used again
+            Some(value) => unsafe {
+                value.drop_in_place();
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Move the value the key refers to out of the slab, and free it's slot
+    pub fn take(&mut self, key: Key) -> Option<T> {
+        // SAFETY: the slot was occupied, so the value is initialized
+        // and the slot is now free, so the value won't be used again
+        self.vacate(key).map(|value| unsafe { value.read() })
+    }
+
+    /// Drop all values in the slab, this keeps the allocated memory
+    pub fn clear(&mut self) {
+        self.free = NO_FREE_SLOT;
+        self.len = 0;
+
+        // if a destructor panics, the remaining values are leaked
+        self.slots.drain(..).map(SlotGuard).for_each(drop);
+    }
+
+    /// Iterate over the keys and values in the slab
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.slots.iter().enumerate(),
+        }
+    }
+}
+
+/// Drops the value in a slot if it's occupied
+struct SlotGuard<T>(Slot<T>);
+
+impl<T> Drop for SlotGuard<T> {
+    fn drop(&mut self) {
+        if self.0.occupied {
+            // SAFETY: the slot is occupied, so the value is initialized
+            unsafe { self.0.value.assume_init_drop() }
+        }
+    }
+}
+
+impl<T> Drop for Slab<T> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for Slab<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the keys and values in a [`Slab`]
+pub struct Iter<'a, T> {
+    slots: core::iter::Enumerate<core::slice::Iter<'a, Slot<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Key, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|(index, slot)| {
+            slot.occupied.then(|| {
+                let key = Key {
+                    index,
+                    generation: slot.generation,
+                };
+                // SAFETY: the slot is occupied, so the value is initialized
+                (key, unsafe { slot.value.assume_init_ref() })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slab() {
+        let mut slab = Slab::<[u32; 64]>::new();
+        let a = slab.insert_with(crate::array::ArrayFromFn::new(|i| i as u32));
+        let b = slab.insert_with(crate::zeroed::ZeroInit);
+        assert_eq!(slab.len(), 2);
+        assert_eq!(slab.get(a).map(|x| x[63]), Some(63));
+
+        assert!(slab.remove(a));
+        assert!(!slab.remove(a));
+        assert!(slab.get(a).is_none());
+
+        let err = slab.try_insert_with(crate::slice::SliceIterInit::new(0..1));
+        assert!(err.is_err());
+        assert_eq!(slab.len(), 1);
+
+        let c = slab.insert_with(crate::zeroed::ZeroInit);
+        assert_eq!(c.index(), a.index());
+        assert_ne!(c, a);
+
+        slab.get_mut(b).unwrap()[0] = 7;
+        assert_eq!(slab.take(b).map(|x| x[0]), Some(7));
+        assert_eq!(slab.iter().map(|(key, _)| key).collect::<Vec<_>>(), [c]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let value = ::alloc::rc::Rc::new(());
+        let mut slab = Slab::new();
+        let key = slab.insert_with(value.clone());
+        slab.insert_with(value.clone());
+        slab.remove(key);
+        assert_eq!(::alloc::rc::Rc::strong_count(&value), 2);
+        slab.clear();
+        assert_eq!(::alloc::rc::Rc::strong_count(&value), 1);
+        assert!(slab.capacity() >= 2);
+
+        slab.insert_with(value.clone());
+        drop(slab);
+        assert_eq!(::alloc::rc::Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_retired_slot() {
+        let mut slab = Slab::new();
+        let key = slab.insert_with(1u8);
+        slab.slots[key.index].generation = u32::MAX;
+        let key = Key {
+            generation: u32::MAX,
+            ..key
+        };
+
+        assert_eq!(slab.take(key), Some(1));
+        let other = slab.insert_with(2);
+        assert_ne!(other.index(), key.index());
+        assert!(slab.get(key).is_none());
+        assert_eq!(slab.len(), 1);
+    }
+}
@@ -3,15 +3,45 @@
 //! this allows you to safely initialize the entire uninitialized slice efficiently,
 //! and drop initialized elements on error.
 
+#[cfg(feature = "std")]
+mod par;
 mod pin_writer;
+mod rev_writer;
 mod writer;
 
+#[cfg(feature = "std")]
+pub use par::ParSliceInit;
 pub use pin_writer::PinSliceWriter;
+pub use rev_writer::SliceWriterRev;
 pub use writer::SliceWriter;
 
-use crate::traits::{TryInitialize, TryPinInitialize};
+use crate::{
+    layout::{SliceLayoutProvider, TailSlice},
+    traits::{SizeHint, TryInitialize, TryPinInitialize},
+};
+
+use core::{convert::Infallible, iter::TrustedLen, mem::MaybeUninit};
+
+/// Initialize a caller-provided uninitialized buffer, such as [`Vec::spare_capacity_mut`],
+/// and return the number of initialized elements
+///
+/// On success the entire buffer is initialized and ownership of the elements passes to the caller,
+/// who is responsible for dropping them (for example via `Vec::set_len`), otherwise they are leaked.
+/// On error, any elements initialized so far are dropped by the initializer.
+///
+/// [`Vec::spare_capacity_mut`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.spare_capacity_mut
+pub fn init_spare<T, I: TryInitialize<[T]>>(
+    spare: &mut [MaybeUninit<T>],
+    init: I,
+) -> Result<usize, I::Error> {
+    let init = crate::Uninit::from_maybe_uninit_slice(spare).try_init(init)?;
+    let len = init.len();
+    core::mem::forget(init);
+    Ok(len)
+}
 
 /// A slice initializer which clones the provided initializer to initialize each element
+#[derive(Debug, Clone, Copy)]
 pub struct SliceInit<I>(I);
 
 impl<I> SliceInit<I> {
@@ -59,6 +89,304 @@ impl<I: TryPinInitialize<T> + Clone, T, const N: usize> TryPinInitialize<[T; N]>
     }
 }
 
+/// A slice initializer which fills every element with a clone of the provided value
+///
+/// The last element receives the value itself, so no clone is wasted.
+/// This is the in-place equivalent of `vec![value; n]`
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatInit<T>(T);
+
+impl<T: Clone> RepeatInit<T> {
+    /// Create a new repeating slice initializer
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Clone> TryInitialize<[T]> for RepeatInit<T> {
+    type Error = Infallible;
+
+    fn try_init(self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let len = ptr.len();
+        let mut writer = SliceWriter::new(ptr);
+
+        if let Some(clones) = len.checked_sub(1) {
+            for _ in 0..clones {
+                writer.write(self.0.clone());
+            }
+            writer.write(self.0);
+        }
+
+        Ok(writer.finish())
+    }
+}
+
+impl<T: Clone> TryPinInitialize<[T]> for RepeatInit<T> {
+    type Error = Infallible;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        let len = ptr.len();
+        let mut writer = PinSliceWriter::new(ptr);
+
+        if let Some(clones) = len.checked_sub(1) {
+            for _ in 0..clones {
+                writer.write(self.0.clone());
+            }
+            writer.write(self.0);
+        }
+
+        Ok(writer.finish())
+    }
+}
+
+impl<T: Clone, const N: usize> TryInitialize<[T; N]> for RepeatInit<T> {
+    type Error = Infallible;
+
+    fn try_init(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<T: Clone, const N: usize> TryPinInitialize<[T; N]> for RepeatInit<T> {
+    type Error = Infallible;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+/// A slice initializer which initializes the first `first_len` elements with one slice initializer
+/// and the rest of the slice with another slice initializer
+///
+/// To concatenate more than two initializers, use [`ConcatInit::then`]
+pub struct ConcatInit<A, B> {
+    first: A,
+    first_len: usize,
+    second: B,
+}
+
+impl<A, B> ConcatInit<A, B> {
+    /// Create a new concatenating slice initializer
+    ///
+    /// `first` initializes the first `first_len` elements, and `second` initializes the rest
+    pub fn new(first: A, first_len: usize, second: B) -> Self {
+        Self {
+            first,
+            first_len,
+            second,
+        }
+    }
+
+    /// Give the second initializer exactly `len` elements, and initialize the rest
+    /// of the slice with `next`
+    pub fn then<C>(self, len: usize, next: C) -> ConcatInit<Self, C> {
+        ConcatInit {
+            first_len: self.first_len.saturating_add(len),
+            first: self,
+            second: next,
+        }
+    }
+}
+
+/// The Error type of `ConcatInit`
+#[derive(Debug)]
+pub enum ConcatInitError<A, B> {
+    /// If the slice is shorter than the number of elements owned by the first initializer
+    NotEnoughSpace,
+    /// If the first initializer errored
+    First(A),
+    /// If the second initializer errored
+    Second(B),
+}
+
+impl<A: TryInitialize<[T]>, B: TryInitialize<[T]>, T> TryInitialize<[T]> for ConcatInit<A, B> {
+    type Error = ConcatInitError<A::Error, B::Error>;
+
+    fn try_init(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        if self.first_len > ptr.len() {
+            return Err(ConcatInitError::NotEnoughSpace);
+        }
+
+        let (head, tail) = ptr.split_at_mut(self.first_len);
+        let head = head.try_init(self.first).map_err(ConcatInitError::First)?;
+        let tail = tail
+            .try_init(self.second)
+            .map_err(ConcatInitError::Second)?;
+
+        // the two halves are now owned by `ptr`
+        core::mem::forget(head);
+        core::mem::forget(tail);
+
+        // SAFETY: both halves of the slice were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<A: TryPinInitialize<[T]>, B: TryPinInitialize<[T]>, T> TryPinInitialize<[T]>
+    for ConcatInit<A, B>
+{
+    type Error = ConcatInitError<A::Error, B::Error>;
+
+    fn try_pin_init(
+        self,
+        mut ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        if self.first_len > ptr.len() {
+            return Err(ConcatInitError::NotEnoughSpace);
+        }
+
+        let (head, tail) = ptr.split_at_mut(self.first_len);
+        let head = head.try_init(self.first).map_err(ConcatInitError::First)?;
+        let tail = tail
+            .try_init(self.second)
+            .map_err(ConcatInitError::Second)?;
+
+        // the two halves are now owned by `ptr`
+        core::mem::forget(head);
+        core::mem::forget(tail);
+
+        // SAFETY: both halves of the slice were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// An initializer for [`TailSlice`] types, which initialize the header
+/// and then the trailing slice
+///
+/// If the trailing slice fails to initialize, then the header is dropped
+#[derive(Debug, Clone, Copy)]
+pub struct TailSliceInit<H, D> {
+    header: H,
+    data: D,
+}
+
+impl<H, D> TailSliceInit<H, D> {
+    /// Create a new initializer for a [`TailSlice`] type
+    pub fn new(header: H, data: D) -> Self {
+        Self { header, data }
+    }
+}
+
+/// The Error type of `TailSliceInit`
+#[derive(Debug)]
+pub enum TailSliceInitError<H, D> {
+    /// If the header initializer errored
+    Header(H),
+    /// If the trailing slice initializer errored
+    Data(D),
+}
+
+/// Split a pointer to a `TailSlice` into pointers to it's header and trailing slice
+fn split_tail_slice<U: ?Sized + TailSlice>(ptr: *mut U) -> (*mut U::Header, *mut [U::Elem]) {
+    let len = core::ptr::metadata(ptr);
+    let ptr = ptr.cast::<u8>();
+    let (_, offset) = crate::layout::tail_slice_layout::<U::Header, U::Elem>(len)
+        .expect("the layout of an existing value is always valid");
+    // SAFETY: the trailing slice is at `offset` bytes into the value
+    let data = unsafe { ptr.add(offset) };
+    (
+        ptr.cast(),
+        core::ptr::slice_from_raw_parts_mut(data.cast(), len),
+    )
+}
+
+impl<U, H, D> TryInitialize<U> for TailSliceInit<H, D>
+where
+    U: ?Sized + TailSlice,
+    H: TryInitialize<U::Header>,
+    D: TryInitialize<[U::Elem]>,
+{
+    type Error = TailSliceInitError<H::Error, D::Error>;
+
+    fn try_init(self, mut ptr: crate::Uninit<U>) -> Result<crate::Init<U>, Self::Error> {
+        let (header, data) = split_tail_slice(ptr.as_mut_ptr());
+        // SAFETY: the header and the trailing slice are disjoint parts of `ptr`
+        let (header, data) = unsafe {
+            (
+                crate::Uninit::from_raw(header),
+                crate::Uninit::from_raw(data),
+            )
+        };
+
+        let header = header
+            .try_init(self.header)
+            .map_err(TailSliceInitError::Header)?;
+        let data = data.try_init(self.data).map_err(TailSliceInitError::Data)?;
+
+        // the header and the trailing slice are now owned by `ptr`
+        core::mem::forget(header);
+        core::mem::forget(data);
+
+        // SAFETY: all fields of `U` were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<U, H, D> TryPinInitialize<U> for TailSliceInit<H, D>
+where
+    U: ?Sized + TailSlice,
+    H: TryPinInitialize<U::Header>,
+    D: TryPinInitialize<[U::Elem]>,
+{
+    type Error = TailSliceInitError<H::Error, D::Error>;
+
+    fn try_pin_init(
+        self,
+        mut ptr: crate::PinnedUninit<U>,
+    ) -> Result<crate::PinnedInit<U>, Self::Error> {
+        let (header, data) = split_tail_slice(ptr.as_mut_ptr());
+        // SAFETY: the header and the trailing slice are disjoint parts of `ptr`
+        // and they are fields of a pinned value, so they are in the pinned type-state
+        let (header, data) = unsafe {
+            (
+                crate::PinnedUninit::new_unchecked(crate::Uninit::from_raw(header)),
+                crate::PinnedUninit::new_unchecked(crate::Uninit::from_raw(data)),
+            )
+        };
+
+        let header = header
+            .try_init(self.header)
+            .map_err(TailSliceInitError::Header)?;
+        let data = data.try_init(self.data).map_err(TailSliceInitError::Data)?;
+
+        // the header and the trailing slice are now owned by `ptr`
+        core::mem::forget(header);
+        core::mem::forget(data);
+
+        // SAFETY: all fields of `U` were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<A: TryInitialize<[T]>, B: TryInitialize<[T]>, T, const N: usize> TryInitialize<[T; N]>
+    for ConcatInit<A, B>
+{
+    type Error = ConcatInitError<A::Error, B::Error>;
+
+    fn try_init(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<A: TryPinInitialize<[T]>, B: TryPinInitialize<[T]>, T, const N: usize> TryPinInitialize<[T; N]>
+    for ConcatInit<A, B>
+{
+    type Error = ConcatInitError<A::Error, B::Error>;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
 /// A slice initializer which clones the provided initializer to initialize each element
 pub struct SliceIterInit<I>(I);
 
@@ -70,11 +398,91 @@ impl<I: Iterator> SliceIterInit<I> {
 }
 
 /// The Error type of `SliceIterInit`
+#[derive(Debug)]
 pub enum SliceIterInitError<T> {
     /// If the underlying iterator didn't yield enough items
-    NotEnoughItems,
+    NotEnoughItems {
+        /// the number of elements that were initialized before the iterator ran out
+        initialized: usize,
+    },
     /// If the initializer produced by the iterator errored
-    Init(T),
+    Init {
+        /// the index of the element that failed to initialize
+        index: usize,
+        /// the error produced by the initializer
+        error: T,
+    },
+    /// If the length of the underlying iterator didn't match the length of the slice
+    ///
+    /// This is only reported by [`ExactSliceIterInit`], before any element is initialized
+    LengthMismatch {
+        /// the length of the slice
+        slice_len: usize,
+        /// the length of the iterator, or `None` if it's longer than `usize::MAX`
+        iter_len: Option<usize>,
+    },
+}
+
+impl<T> SliceIterInitError<T> {
+    /// The number of elements that were successfully initialized before the failure
+    ///
+    /// NOTE: these elements have already been dropped
+    pub fn initialized(&self) -> usize {
+        match *self {
+            Self::NotEnoughItems { initialized } => initialized,
+            Self::Init { index, .. } => index,
+            Self::LengthMismatch { .. } => 0,
+        }
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for SliceIterInitError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotEnoughItems { initialized } => write!(
+                f,
+                "the iterator ran out of items after initializing {initialized} elements"
+            ),
+            Self::Init { index, error } => {
+                write!(f, "failed to initialize element {index}: {error}")
+            }
+            Self::LengthMismatch {
+                slice_len,
+                iter_len: Some(iter_len),
+            } => write!(
+                f,
+                "the iterator has {iter_len} items, but the slice has {slice_len} elements"
+            ),
+            Self::LengthMismatch {
+                slice_len,
+                iter_len: None,
+            } => write!(
+                f,
+                "the iterator has more than usize::MAX items, but the slice has {slice_len} elements"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: std::error::Error + 'static> std::error::Error for SliceIterInitError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotEnoughItems { .. } | Self::LengthMismatch { .. } => None,
+            Self::Init { error, .. } => Some(error),
+        }
+    }
+}
+
+// SAFETY: the layout provider only depends on the length of the iterator, which
+// `TrustedLen` guarantees is accurate, so it doesn't change without calling `next`
+unsafe impl<I: TrustedLen, T> SizeHint<[T]> for SliceIterInit<I> {
+    type LayoutProvider = SliceLayoutProvider;
+
+    fn layout_provider(&self) -> Self::LayoutProvider {
+        // if the length is larger than `usize::MAX`, then computing the layout will fail
+        SliceLayoutProvider(self.0.size_hint().1.unwrap_or(usize::MAX))
+    }
 }
 
 impl<I: Iterator, T> TryInitialize<[T]> for SliceIterInit<I>
@@ -84,10 +492,17 @@ where
     type Error = SliceIterInitError<<I::Item as TryInitialize<T>>::Error>;
 
     fn try_init(mut self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut index = 0;
         SliceWriter::new(ptr).try_for_each(|uninit| {
-            uninit
-                .try_init(self.0.next().ok_or(SliceIterInitError::NotEnoughItems)?)
-                .map_err(SliceIterInitError::Init)
+            let init = self
+                .0
+                .next()
+                .ok_or(SliceIterInitError::NotEnoughItems { initialized: index })?;
+            let init = uninit
+                .try_init(init)
+                .map_err(|error| SliceIterInitError::Init { index, error })?;
+            index += 1;
+            Ok(init)
         })
     }
 }
@@ -102,10 +517,17 @@ where
         mut self,
         ptr: crate::PinnedUninit<[T]>,
     ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        let mut index = 0;
         PinSliceWriter::new(ptr).try_for_each(|uninit| {
-            uninit
-                .try_init(self.0.next().ok_or(SliceIterInitError::NotEnoughItems)?)
-                .map_err(SliceIterInitError::Init)
+            let init = self
+                .0
+                .next()
+                .ok_or(SliceIterInitError::NotEnoughItems { initialized: index })?;
+            let init = uninit
+                .try_init(init)
+                .map_err(|error| SliceIterInitError::Init { index, error })?;
+            index += 1;
+            Ok(init)
         })
     }
 }
@@ -134,3 +556,391 @@ where
         super::array::ArrayInit::new(self).try_pin_init(ptr)
     }
 }
+
+/// A slice initializer which takes an initializer for each element from an iterator with a known length
+///
+/// Unlike [`SliceIterInit`], the length of the iterator is checked against the length of the slice
+/// once, before any element is initialized
+pub struct ExactSliceIterInit<I> {
+    iter: I,
+    trusted: bool,
+}
+
+impl<I: ExactSizeIterator> ExactSliceIterInit<I> {
+    /// Create a new slice initializer
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+            trusted: false,
+        }
+    }
+}
+
+impl<I: TrustedLen> ExactSliceIterInit<I> {
+    /// Create a new slice initializer from an iterator which is guaranteed to report it's length
+    ///
+    /// This skips checking for the end of the iterator for each element
+    pub fn new_trusted(iter: I) -> Self {
+        Self {
+            iter,
+            trusted: true,
+        }
+    }
+}
+
+impl<I: Iterator> ExactSliceIterInit<I> {
+    fn check_len<E>(&self, slice_len: usize) -> Result<(), SliceIterInitError<E>> {
+        match self.iter.size_hint() {
+            (lower, Some(upper)) if lower == upper && upper == slice_len => Ok(()),
+            (_, iter_len) => Err(SliceIterInitError::LengthMismatch {
+                slice_len,
+                iter_len,
+            }),
+        }
+    }
+
+    #[inline]
+    fn next_init<E>(&mut self, index: usize) -> Result<I::Item, SliceIterInitError<E>> {
+        match self.iter.next() {
+            Some(init) => Ok(init),
+            // SAFETY: trusted is only set for `TrustedLen` iterators, and the length
+            // of those was checked against the length of the slice
+            None if self.trusted => unsafe { core::hint::unreachable_unchecked() },
+            None => Err(SliceIterInitError::NotEnoughItems { initialized: index }),
+        }
+    }
+}
+
+// SAFETY: the layout provider only depends on the length of the iterator, which
+// `TrustedLen` guarantees is accurate, so it doesn't change without calling `next`
+unsafe impl<I: TrustedLen, T> SizeHint<[T]> for ExactSliceIterInit<I> {
+    type LayoutProvider = SliceLayoutProvider;
+
+    fn layout_provider(&self) -> Self::LayoutProvider {
+        // if the length is larger than `usize::MAX`, then computing the layout will fail
+        SliceLayoutProvider(self.iter.size_hint().1.unwrap_or(usize::MAX))
+    }
+}
+
+impl<I: Iterator, T> TryInitialize<[T]> for ExactSliceIterInit<I>
+where
+    I::Item: TryInitialize<T>,
+{
+    type Error = SliceIterInitError<<I::Item as TryInitialize<T>>::Error>;
+
+    fn try_init(mut self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        self.check_len(ptr.len())?;
+
+        let mut index = 0;
+        SliceWriter::new(ptr).try_for_each(|uninit| {
+            let init = self.next_init(index)?;
+            let init = uninit
+                .try_init(init)
+                .map_err(|error| SliceIterInitError::Init { index, error })?;
+            index += 1;
+            Ok(init)
+        })
+    }
+}
+
+impl<I: Iterator, T> TryPinInitialize<[T]> for ExactSliceIterInit<I>
+where
+    I::Item: TryPinInitialize<T>,
+{
+    type Error = SliceIterInitError<<I::Item as TryPinInitialize<T>>::Error>;
+
+    fn try_pin_init(
+        mut self,
+        ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        self.check_len(ptr.len())?;
+
+        let mut index = 0;
+        PinSliceWriter::new(ptr).try_for_each(|uninit| {
+            let init = self.next_init(index)?;
+            let init = uninit
+                .try_init(init)
+                .map_err(|error| SliceIterInitError::Init { index, error })?;
+            index += 1;
+            Ok(init)
+        })
+    }
+}
+
+impl<I: Iterator, T, const N: usize> TryInitialize<[T; N]> for ExactSliceIterInit<I>
+where
+    I::Item: TryInitialize<T>,
+{
+    type Error = SliceIterInitError<<I::Item as TryInitialize<T>>::Error>;
+
+    fn try_init(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+impl<I: Iterator, T, const N: usize> TryPinInitialize<[T; N]> for ExactSliceIterInit<I>
+where
+    I::Item: TryPinInitialize<T>,
+{
+    type Error = SliceIterInitError<<I::Item as TryPinInitialize<T>>::Error>;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+/// A slice initializer which moves the elements out of a `Vec<T>` or `Box<[T]>`
+#[cfg(feature = "alloc")]
+pub struct MoveSliceInit<T> {
+    items: alloc::vec::Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> MoveSliceInit<T> {
+    /// Create a new slice initializer which moves the elements out of `items`
+    pub fn new(items: alloc::vec::Vec<T>) -> Self {
+        Self { items }
+    }
+
+    /// Create a new slice initializer which moves the elements out of `items`
+    pub fn from_boxed_slice(items: alloc::boxed::Box<[T]>) -> Self {
+        Self::new(items.into_vec())
+    }
+
+    fn move_into(mut self, ptr: *mut [T]) -> Result<(), MoveSliceInitError<T>> {
+        if self.items.len() != ptr.len() {
+            return Err(MoveSliceInitError {
+                items: self.items,
+                slice_len: ptr.len(),
+            });
+        }
+
+        // SAFETY: the pointer is valid for writes of `ptr.len()` elements,
+        // and the vec owns `ptr.len()` elements which can't overlap the slice
+        unsafe {
+            ptr.cast::<T>()
+                .copy_from_nonoverlapping(self.items.as_ptr(), ptr.len())
+        }
+
+        // SAFETY: the elements were moved out of the vec
+        unsafe { self.items.set_len(0) }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<alloc::vec::Vec<T>> for MoveSliceInit<T> {
+    fn from(items: alloc::vec::Vec<T>) -> Self {
+        Self::new(items)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<alloc::boxed::Box<[T]>> for MoveSliceInit<T> {
+    fn from(items: alloc::boxed::Box<[T]>) -> Self {
+        Self::from_boxed_slice(items)
+    }
+}
+
+/// The Error type of `MoveSliceInit`, the length of the slice didn't match the number of items
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct MoveSliceInitError<T> {
+    /// the items which couldn't be moved
+    pub items: alloc::vec::Vec<T>,
+    /// the length of the slice
+    pub slice_len: usize,
+}
+
+#[cfg(feature = "alloc")]
+// SAFETY: the layout provider only depends on the length, which doesn't change
+unsafe impl<T> SizeHint<[T]> for MoveSliceInit<T> {
+    type LayoutProvider = SliceLayoutProvider;
+
+    fn layout_provider(&self) -> Self::LayoutProvider {
+        SliceLayoutProvider(self.items.len())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryInitialize<[T]> for MoveSliceInit<T> {
+    type Error = MoveSliceInitError<T>;
+
+    fn try_init(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        self.move_into(ptr.as_mut_ptr())?;
+        // SAFETY: the slice was initialized by `move_into`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryPinInitialize<[T]> for MoveSliceInit<T> {
+    type Error = MoveSliceInitError<T>;
+
+    fn try_pin_init(
+        self,
+        mut ptr: crate::PinnedUninit<[T]>,
+    ) -> Result<crate::PinnedInit<[T]>, Self::Error> {
+        self.move_into(ptr.as_mut_ptr())?;
+        // SAFETY: the slice was initialized by `move_into`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryInitialize<[T; N]> for MoveSliceInit<T> {
+    type Error = MoveSliceInitError<T>;
+
+    fn try_init(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryPinInitialize<[T; N]> for MoveSliceInit<T> {
+    type Error = MoveSliceInitError<T>;
+
+    fn try_pin_init(
+        self,
+        ptr: crate::PinnedUninit<[T; N]>,
+    ) -> Result<crate::PinnedInit<[T; N]>, Self::Error> {
+        super::array::ArrayInit::new(self).try_pin_init(ptr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pinned_write_iter() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 3];
+
+        let uninit = crate::PinnedUninit::from_maybe_uninit_slice(core::pin::Pin::new(&mut array));
+        let err = uninit.write_iter([1, 2]).map(drop);
+        assert!(matches!(
+            err,
+            Err(SliceIterInitError::LengthMismatch {
+                slice_len: 3,
+                iter_len: Some(2)
+            })
+        ));
+
+        let uninit = crate::PinnedUninit::from_maybe_uninit_slice(core::pin::Pin::new(&mut array));
+        let init = uninit.write_iter([1, 2, 3]).unwrap();
+        assert_eq!(*init, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_concat() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 6];
+        let uninit = crate::Uninit::from_maybe_uninit_slice(&mut array);
+
+        let init = ConcatInit::new(RepeatInit::new(1), 2, RepeatInit::new(2))
+            .then(3, RepeatInit::new(3))
+            .try_init(uninit);
+
+        match init {
+            Ok(init) => assert_eq!(*init, [1, 1, 2, 2, 2, 3]),
+            Err(_) => panic!("concatenation failed"),
+        }
+    }
+
+    #[test]
+    fn test_concat_not_enough_space() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 2];
+        let uninit = crate::Uninit::from_maybe_uninit_slice(&mut array);
+
+        let init = ConcatInit::new(RepeatInit::new(1), 3, RepeatInit::new(2)).try_init(uninit);
+
+        assert!(matches!(init, Err(ConcatInitError::NotEnoughSpace)));
+    }
+
+    #[test]
+    fn test_iter_not_enough_items() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 4];
+        let uninit = crate::Uninit::from_maybe_uninit_slice(&mut array);
+
+        let err = SliceIterInit::new(0..3).try_init(uninit).err().unwrap();
+
+        assert_eq!(err.initialized(), 3);
+        assert!(matches!(
+            err,
+            SliceIterInitError::NotEnoughItems { initialized: 3 }
+        ));
+    }
+
+    #[test]
+    fn test_exact_iter_length_mismatch() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 4];
+        let uninit = crate::Uninit::from_maybe_uninit_slice(&mut array);
+
+        let err = ExactSliceIterInit::new_trusted(0..5)
+            .try_init(uninit)
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err,
+            SliceIterInitError::LengthMismatch {
+                slice_len: 4,
+                iter_len: Some(5)
+            }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_tail_slice() {
+        use crate::layout::TailSliceLayoutProvider;
+
+        #[repr(C)]
+        struct Node {
+            header: u8,
+            data: [u32],
+        }
+
+        // SAFETY: `Node` is `#[repr(C)]`, with a `u8` header followed by a `[u32]`
+        unsafe impl TailSlice for Node {
+            type Header = u8;
+            type Elem = u32;
+        }
+
+        let provider = TailSliceLayoutProvider::<u8, u32>::new(3);
+        assert_eq!(provider.data_offset().unwrap(), 4);
+
+        let node: alloc::boxed::Box<Node> =
+            crate::boxed::emplace(provider, TailSliceInit::new(7, RepeatInit::new(2)));
+        assert_eq!(node.header, 7);
+        assert_eq!(node.data, [2, 2, 2]);
+        assert_eq!(core::mem::size_of_val(&*node), 16);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_raw_layout_provider() {
+        use crate::layout::RawLayoutProvider;
+
+        let layout = core::alloc::Layout::from_size_align(6, 2).unwrap();
+        // SAFETY: a `[u16]` of length 3 has a size of 6 and an alignment of 2
+        let provider = unsafe { RawLayoutProvider::<[u16]>::new(layout, 3) };
+        let boxed: alloc::boxed::Box<[u16]> = crate::boxed::emplace(provider, RepeatInit::new(1));
+        assert_eq!(*boxed, [1, 1, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_init_spare() {
+        let mut vec = ::alloc::vec![10u32];
+        vec.reserve(3);
+        let spare = &mut vec.spare_capacity_mut()[..3];
+        let len = init_spare(spare, SliceIterInit::new(0..)).unwrap();
+        // SAFETY: `init_spare` initialized `len` elements after the current length
+        unsafe { vec.set_len(vec.len() + len) }
+        assert_eq!(vec, [10, 0, 1, 2]);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{traits::TryInitialize, Init, Uninit};
+
+/// A slice initializer which splits the slice into chunks, and initializes each chunk
+/// on it's own thread by cloning the provided slice initializer
+///
+/// If any chunk fails to initialize, all other chunks are dropped, and one of the errors is returned
+pub struct ParSliceInit<I> {
+    init: I,
+    chunks: usize,
+}
+
+impl<I> ParSliceInit<I> {
+    /// Create a new parallel slice initializer, which splits the slice into `chunks` chunks
+    ///
+    /// At most `chunks - 1` threads will be spawned in addition to the current thread
+    pub fn new(init: I, chunks: usize) -> Self {
+        Self {
+            init,
+            chunks: chunks.max(1),
+        }
+    }
+}
+
+impl<I, T> TryInitialize<[T]> for ParSliceInit<I>
+where
+    I: TryInitialize<[T]> + Clone + Send,
+    I::Error: Send,
+    T: Send,
+{
+    type Error = I::Error;
+
+    fn try_init(self, ptr: Uninit<[T]>) -> Result<Init<[T]>, Self::Error> {
+        par_try_init(self.init, ptr, self.chunks)
+    }
+}
+
+impl<I, T, const N: usize> TryInitialize<[T; N]> for ParSliceInit<I>
+where
+    I: TryInitialize<[T]> + Clone + Send,
+    I::Error: Send,
+    T: Send,
+{
+    type Error = I::Error;
+
+    fn try_init(self, ptr: Uninit<[T; N]>) -> Result<Init<[T; N]>, Self::Error> {
+        crate::array::ArrayInit::new(self).try_init(ptr)
+    }
+}
+
+fn par_try_init<I, T>(init: I, mut ptr: Uninit<[T]>, chunks: usize) -> Result<Init<[T]>, I::Error>
+where
+    I: TryInitialize<[T]> + Clone + Send,
+    I::Error: Send,
+    T: Send,
+{
+    let len = ptr.len();
+
+    if chunks <= 1 || len <= 1 {
+        return init.try_init(ptr);
+    }
+
+    // split the slice proportionally to the number of chunks on each side
+    let left_chunks = chunks / 2;
+    let mid = len / chunks * left_chunks + (len % chunks).min(left_chunks);
+
+    let (left, right) = ptr.split_at_mut(mid);
+    let right_init = init.clone();
+
+    let (left, right) = std::thread::scope(|s| {
+        let right = s.spawn(move || par_try_init(right_init, right, chunks - left_chunks));
+        let left = par_try_init(init, left, left_chunks);
+        match right.join() {
+            Ok(right) => (left, right),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    });
+
+    let left = left?;
+    let right = right?;
+
+    // the two halves are now owned by `ptr`
+    core::mem::forget(left);
+    core::mem::forget(right);
+
+    // SAFETY: both halves of the slice were initialized
+    Ok(unsafe { ptr.assume_init() })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_par() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 37];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let init = uninit.try_init(ParSliceInit::new(crate::slice::RepeatInit::new(7), 5));
+
+        match init {
+            Ok(init) => assert!(init.iter().all(|&x| x == 7)),
+            Err(inf) => match inf {},
+        }
+    }
+}
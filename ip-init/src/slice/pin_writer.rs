@@ -2,7 +2,7 @@ use core::marker::PhantomData;
 
 use crate::{
     traits::{PinInitialize, TryPinInitialize},
-    PinnedInit, PinnedUninit,
+    Init, PinnedInit, PinnedUninit, Uninit,
 };
 
 /// A writer to a pinned uninitialized slice
@@ -25,6 +25,16 @@ unsafe impl<#[may_dangle] T> Drop for PinSliceWriter<'_, T> {
     }
 }
 
+impl<T> core::fmt::Debug for PinSliceWriter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PinSliceWriter")
+            .field("type", &core::any::type_name::<T>())
+            .field("len", &self.len())
+            .field("initialized", &self.initialized())
+            .finish()
+    }
+}
+
 impl<'a, T> PinSliceWriter<'a, T> {
     /// create a new writer
     pub fn new(mut uninit: PinnedUninit<'a, [T]>) -> Self {
@@ -52,6 +62,25 @@ impl<'a, T> PinSliceWriter<'a, T> {
         Ok(self.finish())
     }
 
+    /// Try to apply the function to all remaining unintialized slots in the slice
+    /// and return the fully initialized slice, unless the function fails.
+    /// In which case return the error and the writer, without dropping any initialized elements.
+    ///
+    /// The writer can be used to retry initialization, or to recover the initialized
+    /// prefix with [`PinSliceWriter::finish_partial`]
+    pub fn try_for_each_partial<E>(
+        mut self,
+        mut f: impl FnMut(PinnedUninit<'_, T>) -> Result<PinnedInit<'_, T>, E>,
+    ) -> Result<PinnedInit<'a, [T]>, (E, Self)> {
+        while !self.is_finished() {
+            if let Err(err) = self.try_write(crate::func::TryPinInitFn::new(&mut f)) {
+                return Err((err, self));
+            }
+        }
+
+        Ok(self.finish())
+    }
+
     /// Apply the function to all remaining unintialized slots in the slice
     /// and return the fully initialized slice
     pub fn for_each(
@@ -59,8 +88,7 @@ impl<'a, T> PinSliceWriter<'a, T> {
         mut f: impl FnMut(PinnedUninit<'_, T>) -> PinnedInit<'_, T>,
     ) -> PinnedInit<'a, [T]> {
         while !self.is_finished() {
-            self.try_write(crate::func::PinInitFn::new(&mut f))
-                .unwrap_or_else(|inf| match inf {})
+            self.write(crate::func::PinInitFn::new(&mut f))
         }
 
         self.finish()
@@ -91,12 +119,146 @@ impl<'a, T> PinSliceWriter<'a, T> {
         unsafe { uninit.assume_init() }
     }
 
+    /// finish the writer and get the initialized prefix and the uninitialized remainder of the slice
+    ///
+    /// Unlike [`PinSliceWriter::finish`], this never panics
+    pub fn finish_partial(mut self) -> (PinnedInit<'a, [T]>, PinnedUninit<'a, [T]>) {
+        let ptr = self.uninit.as_mut_ptr().cast::<T>();
+        let init = core::ptr::slice_from_raw_parts_mut(ptr, self.initialized());
+        let uninit = core::ptr::slice_from_raw_parts_mut(self.current, self.remaining());
+        core::mem::forget(self);
+
+        // SAFETY: the first `initialized` elements are initialized, and the rest are
+        // disjoint and allocated for the rest of the lifetime `'a`
+        // both halves are sub-slices of a pinned slice, so they are in the pinned type-state
+        unsafe {
+            (
+                PinnedInit::new_unchecked(Init::from_raw(init)),
+                PinnedUninit::new_unchecked(Uninit::from_raw(uninit)),
+            )
+        }
+    }
+
     /// Has the writer written to the entire slice
     #[inline(always)]
     pub fn is_finished(&self) -> bool {
         self.remaining == 0
     }
 
+    /// The length of the entire slice
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.uninit.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of elements which are still uninitialized
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// The number of elements which were initialized
+    #[inline(always)]
+    pub fn initialized(&self) -> usize {
+        self.len() - self.remaining
+    }
+
+    /// The initialized prefix of the slice
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let ptr = self.uninit.as_ptr().cast::<T>();
+        // SAFETY: the first `initialized` elements of the slice are initialized
+        unsafe { core::slice::from_raw_parts(ptr, self.initialized()) }
+    }
+
+    /// A pinned mutable reference to the initialized prefix of the slice
+    ///
+    /// # Safety
+    ///
+    /// The writer doesn't guarantee that the initialized elements are dropped before their
+    /// memory is reused, but `Pin` does. So the caller must ensure that this writer (or the
+    /// `PinnedInit` it finishes into) is dropped before the memory is reused, i.e. it isn't
+    /// leaked. See [`PinnedInit::as_mut`](crate::PinnedInit::as_mut)
+    #[inline]
+    pub unsafe fn as_pin_mut(&mut self) -> core::pin::Pin<&mut [T]> {
+        let len = self.initialized();
+        let ptr = self.uninit.as_mut_ptr().cast::<T>();
+        // SAFETY: the first `initialized` elements of the slice are initialized
+        // and in the pinned type-state, and the caller ensures that they will be
+        // dropped before their memory is reused
+        unsafe { core::pin::Pin::new_unchecked(core::slice::from_raw_parts_mut(ptr, len)) }
+    }
+
+    /// Clone each element of `slice` into the next `slice.len()` slots
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `slice.len()` remaining slots, this function will panic
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        assert!(slice.len() <= self.remaining());
+
+        for value in slice {
+            // SAFETY: there are at least `slice.len()` remaining slots
+            unsafe { self.init_unchecked(value.clone()) }
+        }
+    }
+
+    /// Initialize the remaining slots from the initializers yielded by `iter`,
+    /// until either the iterator or the writer is exhausted
+    ///
+    /// No items are taken from the iterator once the writer is finished
+    ///
+    /// Returns the number of slots which were initialized
+    pub fn extend_from_iter<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: PinInitialize<T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut count = 0;
+
+        while !self.is_finished() {
+            let Some(init) = iter.next() else { break };
+            // SAFETY: we're not finished yet
+            unsafe { self.init_unchecked(init) }
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Copy `slice` into the next `slice.len()` slots
+    ///
+    /// This is a single `memcpy`, unlike [`PinSliceWriter::extend_from_slice`]
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `slice.len()` remaining slots, this function will panic
+    pub fn copy_from_slice(&mut self, slice: &[T])
+    where
+        T: Copy,
+    {
+        assert!(slice.len() <= self.remaining());
+        // SAFETY: there are at least `slice.len()` remaining slots, and they can't
+        // overlap with `slice` because they are uninitialized
+        unsafe {
+            self.current
+                .copy_from_nonoverlapping(slice.as_ptr(), slice.len())
+        }
+        // SAFETY: the next `slice.len()` slots were just initialized
+        self.current = unsafe { self.current.add(slice.len()) };
+        self.remaining -= slice.len();
+    }
+
     /// Try to initialize the next slot
     ///
     /// # Panics
@@ -175,3 +337,24 @@ impl<'a, T> PinSliceWriter<'a, T> {
         self.remaining -= 1;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::mem::MaybeUninit;
+
+    use super::*;
+
+    #[test]
+    fn test_finish_partial() {
+        let mut slot = [MaybeUninit::<u32>::uninit(); 4];
+        // SAFETY: the slot is never moved after this point
+        let slot = unsafe { core::pin::Pin::new_unchecked(&mut slot[..]) };
+        let mut writer = PinSliceWriter::new(PinnedUninit::from_maybe_uninit_slice(slot));
+        writer.copy_from_slice(&[1, 2]);
+        assert_eq!(writer.extend_from_iter([3]), 1);
+        assert_eq!(writer.as_slice(), [1, 2, 3]);
+        let (init, uninit) = writer.finish_partial();
+        assert_eq!(*init, [1, 2, 3]);
+        assert_eq!(uninit.len(), 1);
+    }
+}
@@ -0,0 +1,252 @@
+use core::marker::PhantomData;
+
+use crate::{
+    traits::{Initialize, TryInitialize},
+    Init, Uninit,
+};
+
+/// A writer to an uninitialized slice, which initializes the slice from back to front
+pub struct SliceWriterRev<'a, T> {
+    uninit: Uninit<'a, [T]>,
+    /// one past the next slot to write
+    current: *mut T,
+    remaining: usize,
+    _lt: PhantomData<Uninit<'a, T>>,
+}
+
+// SAFETY: this only drops the T, so is trivially correct for `#[may_dangle]`
+unsafe impl<#[may_dangle] T> Drop for SliceWriterRev<'_, T> {
+    fn drop(&mut self) {
+        let len = self.uninit.len();
+        let len = len.wrapping_sub(self.remaining);
+        let ptr = core::ptr::slice_from_raw_parts_mut(self.current, len);
+        // SAFETY: this only drops the initialized portion of the writer
+        unsafe { ptr.drop_in_place() }
+    }
+}
+
+impl<T> core::fmt::Debug for SliceWriterRev<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SliceWriterRev")
+            .field("type", &core::any::type_name::<T>())
+            .field("len", &self.len())
+            .field("initialized", &self.initialized())
+            .finish()
+    }
+}
+
+impl<'a, T> SliceWriterRev<'a, T> {
+    /// create a new writer
+    pub fn new(mut uninit: Uninit<'a, [T]>) -> Self {
+        let len = uninit.len();
+        let ptr = uninit.as_mut_ptr().cast::<T>();
+        Self {
+            uninit,
+            // SAFETY: the one past the end pointer is still in the same allocation
+            current: unsafe { ptr.add(len) },
+            remaining: len,
+            _lt: PhantomData,
+        }
+    }
+
+    /// Try to apply the function to all remaining unintialized slots in the slice, from back to front,
+    /// and return the fully initialized slice, unless the function fails.
+    /// In which case return the error.
+    pub fn try_for_each<E>(
+        mut self,
+        mut f: impl FnMut(Uninit<'_, T>) -> Result<Init<'_, T>, E>,
+    ) -> Result<Init<'a, [T]>, E> {
+        while !self.is_finished() {
+            self.try_write(crate::func::TryInitFn::new(&mut f))?
+        }
+
+        Ok(self.finish())
+    }
+
+    /// Apply the function to all remaining unintialized slots in the slice, from back to front,
+    /// and return the fully initialized slice
+    pub fn for_each(mut self, mut f: impl FnMut(Uninit<'_, T>) -> Init<'_, T>) -> Init<'a, [T]> {
+        while !self.is_finished() {
+            self.write(crate::func::InitFn::new(&mut f))
+        }
+
+        self.finish()
+    }
+
+    /// finish the writer and get an initialized slice
+    ///
+    /// # Panics
+    ///
+    /// if the writer isn't finished, this function will panic
+    #[inline]
+    pub fn finish(self) -> Init<'a, [T]> {
+        assert!(self.is_finished());
+        // SAFETY: this writer is finished
+        unsafe { self.finish_unchecked() }
+    }
+
+    /// finish the writer and get an initialized slice
+    ///
+    /// # Safety
+    ///
+    /// if the writer must be finished
+    #[inline]
+    pub unsafe fn finish_unchecked(mut self) -> Init<'a, [T]> {
+        let uninit = core::mem::take(&mut self.uninit);
+        core::mem::forget(self);
+        // SAFETY: a finished writer has initialized every element of the slice
+        unsafe { uninit.assume_init() }
+    }
+
+    /// finish the writer and get the uninitialized prefix and the initialized suffix of the slice
+    ///
+    /// Unlike [`SliceWriterRev::finish`], this never panics
+    pub fn finish_partial(mut self) -> (Uninit<'a, [T]>, Init<'a, [T]>) {
+        let initialized = self.initialized();
+        let remaining = self.remaining;
+        let current = self.current;
+        let ptr = self.uninit.as_mut_ptr().cast::<T>();
+        core::mem::forget(self);
+
+        let uninit = core::ptr::slice_from_raw_parts_mut(ptr, remaining);
+        let init = core::ptr::slice_from_raw_parts_mut(current, initialized);
+
+        // SAFETY: the last `initialized` elements are initialized, and the rest are
+        // disjoint and allocated for the rest of the lifetime `'a`
+        unsafe { (Uninit::from_raw(uninit), Init::from_raw(init)) }
+    }
+
+    /// Has the writer written to the entire slice
+    #[inline(always)]
+    pub fn is_finished(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// The length of the entire slice
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.uninit.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of elements which are still uninitialized
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// The number of elements which were initialized
+    #[inline(always)]
+    pub fn initialized(&self) -> usize {
+        self.len() - self.remaining
+    }
+
+    /// The initialized suffix of the slice
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the `initialized` elements after current are initialized
+        unsafe { core::slice::from_raw_parts(self.current, self.initialized()) }
+    }
+
+    /// The initialized suffix of the slice
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the `initialized` elements after current are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.current, self.initialized()) }
+    }
+
+    /// Try to initialize the last uninitialized slot
+    ///
+    /// # Panics
+    ///
+    /// if the writer is finished, this function will panic
+    pub fn try_write<I: TryInitialize<T>>(&mut self, init: I) -> Result<(), I::Error> {
+        assert!(!self.is_finished());
+        // SAFETY: we're not finished yet
+        unsafe { self.try_init_unchecked(init) }
+    }
+
+    /// Initialize the last uninitialized slot
+    ///
+    /// # Panics
+    ///
+    /// if the writer is finished, this function will panic
+    pub fn write<I: Initialize<T>>(&mut self, init: I) {
+        assert!(!self.is_finished());
+        // SAFETY: we're not finished yet
+        unsafe { self.init_unchecked(init) }
+    }
+
+    /// Try to initialize the last uninitialized slot
+    ///
+    /// # Safety
+    ///
+    /// The writer must not be finished yet
+    pub unsafe fn try_init_unchecked<I: TryInitialize<T>>(
+        &mut self,
+        init: I,
+    ) -> Result<(), I::Error> {
+        debug_assert!(!self.is_finished());
+
+        // SAFETY: the writer isn't finished yet, so there is an uninitialized slot before current
+        let next = unsafe { self.current.sub(1) };
+
+        // SAFETY:
+        // * the next pointer came from an uninit
+        // * the writer isn't finished yet
+        // therefore the pointer is still in bounds
+        let output = unsafe { crate::raw::try_init_in_place(init, next) };
+
+        if output.is_ok() {
+            self.current = next;
+            self.remaining -= 1;
+        }
+
+        output
+    }
+
+    /// Initialize the last uninitialized slot
+    ///
+    /// # Safety
+    ///
+    /// The writer must not be finished yet
+    pub unsafe fn init_unchecked<I: Initialize<T>>(&mut self, init: I) {
+        debug_assert!(!self.is_finished());
+
+        // SAFETY: the writer isn't finished yet, so there is an uninitialized slot before current
+        let next = unsafe { self.current.sub(1) };
+
+        // SAFETY:
+        // * the next pointer came from an uninit
+        // * the writer isn't finished yet
+        // therefore the pointer is still in bounds
+        unsafe { crate::raw::init_in_place(init, next) }
+
+        self.current = next;
+        self.remaining -= 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rev() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 4];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let mut value = 0;
+        let init = SliceWriterRev::new(uninit).for_each(|uninit| {
+            value += 1;
+            uninit.write(value)
+        });
+
+        assert_eq!(*init, [4, 3, 2, 1]);
+    }
+}
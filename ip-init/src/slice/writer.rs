@@ -1,4 +1,4 @@
-use core::marker::PhantomData;
+use core::{marker::PhantomData, mem, ptr::NonNull};
 
 use crate::{
     traits::{Initialize, TryInitialize},
@@ -6,38 +6,78 @@ use crate::{
 };
 
 /// A writer to an uninitialized slice
+///
+/// The writer keeps track of the start of the slice, the next uninitialized slot,
+/// and the end of the slice. So writing an element is a single pointer bump.
 pub struct SliceWriter<'a, T> {
-    uninit: Uninit<'a, [T]>,
+    start: NonNull<T>,
+    /// for zero-sized types, this is `start + initialized` bytes
     current: *mut T,
-    remaining: usize,
-    _lt: PhantomData<Uninit<'a, T>>,
+    /// for zero-sized types, this is `start + len` bytes
+    end: *mut T,
+    _lt: PhantomData<Uninit<'a, [T]>>,
 }
 
 // SAFETY: this only drops the T, so is trivially correct for `#[may_dangle]`
 unsafe impl<#[may_dangle] T> Drop for SliceWriter<'_, T> {
     fn drop(&mut self) {
-        let len = self.uninit.len();
-        let ptr = self.uninit.as_mut_ptr().cast::<T>();
-        let len = len.wrapping_sub(self.remaining);
-        let ptr = core::ptr::slice_from_raw_parts_mut(ptr, len);
+        let ptr = core::ptr::slice_from_raw_parts_mut(self.start.as_ptr(), self.initialized());
         // SAFETY: this only drops the initialized portion of the writer
         unsafe { ptr.drop_in_place() }
     }
 }
 
+impl<T> core::fmt::Debug for SliceWriter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SliceWriter")
+            .field("type", &core::any::type_name::<T>())
+            .field("len", &self.len())
+            .field("initialized", &self.initialized())
+            .finish()
+    }
+}
+
 impl<'a, T> SliceWriter<'a, T> {
+    const ZERO_SIZED: bool = mem::size_of::<T>() == 0;
+
     /// create a new writer
-    pub fn new(mut uninit: Uninit<'a, [T]>) -> Self {
+    pub fn new(uninit: Uninit<'a, [T]>) -> Self {
         let len = uninit.len();
-        let ptr = uninit.as_mut_ptr().cast::<T>();
+        let start = uninit.as_non_null_ptr().cast::<T>();
+        let end = if Self::ZERO_SIZED {
+            start.as_ptr().wrapping_byte_add(len)
+        } else {
+            // SAFETY: the one past the end pointer is still in the same allocation
+            unsafe { start.as_ptr().add(len) }
+        };
         Self {
-            uninit,
-            current: ptr,
-            remaining: len,
+            start,
+            current: start.as_ptr(),
+            end,
             _lt: PhantomData,
         }
     }
 
+    #[inline(always)]
+    fn distance(from: *mut T, to: *mut T) -> usize {
+        if Self::ZERO_SIZED {
+            to.addr() - from.addr()
+        } else {
+            // SAFETY: both pointers are in the same allocation, and from <= to
+            unsafe { to.offset_from(from) as usize }
+        }
+    }
+
+    /// The next uninitialized slot
+    #[inline(always)]
+    fn slot(&self) -> *mut T {
+        if Self::ZERO_SIZED {
+            self.start.as_ptr()
+        } else {
+            self.current
+        }
+    }
+
     /// Try to apply the function to all remaining unintialized slots in the slice
     /// and return the fully initialized slice, unless the function fails.
     /// In which case return the error.
@@ -52,6 +92,25 @@ impl<'a, T> SliceWriter<'a, T> {
         Ok(self.finish())
     }
 
+    /// Try to apply the function to all remaining unintialized slots in the slice
+    /// and return the fully initialized slice, unless the function fails.
+    /// In which case return the error and the writer, without dropping any initialized elements.
+    ///
+    /// The writer can be used to retry initialization, or to recover the initialized
+    /// prefix with [`SliceWriter::finish_partial`]
+    pub fn try_for_each_partial<E>(
+        mut self,
+        mut f: impl FnMut(Uninit<'_, T>) -> Result<Init<'_, T>, E>,
+    ) -> Result<Init<'a, [T]>, (E, Self)> {
+        while !self.is_finished() {
+            if let Err(err) = self.try_write(crate::func::TryInitFn::new(&mut f)) {
+                return Err((err, self));
+            }
+        }
+
+        Ok(self.finish())
+    }
+
     /// Apply the function to all remaining unintialized slots in the slice
     /// and return the fully initialized slice
     pub fn for_each(mut self, mut f: impl FnMut(Uninit<'_, T>) -> Init<'_, T>) -> Init<'a, [T]> {
@@ -62,6 +121,37 @@ impl<'a, T> SliceWriter<'a, T> {
         self.finish()
     }
 
+    /// Try to apply the function to remaining unintialized chunks of `N` slots in the slice,
+    /// until there are fewer than `N` slots remaining, unless the function fails.
+    /// In which case return the error.
+    ///
+    /// The remaining slots can be initialized with the other methods on the writer
+    pub fn try_for_each_chunk<const N: usize, E>(
+        &mut self,
+        mut f: impl FnMut(Uninit<'_, [T; N]>) -> Result<Init<'_, [T; N]>, E>,
+    ) -> Result<(), E> {
+        while self.remaining() >= N && N != 0 {
+            // SAFETY: there are at least `N` remaining slots
+            unsafe { self.try_init_chunk_unchecked(crate::func::TryInitFn::new(&mut f))? }
+        }
+
+        Ok(())
+    }
+
+    /// Apply the function to remaining unintialized chunks of `N` slots in the slice,
+    /// until there are fewer than `N` slots remaining.
+    ///
+    /// The remaining slots can be initialized with the other methods on the writer
+    pub fn for_each_chunk<const N: usize>(
+        &mut self,
+        mut f: impl FnMut(Uninit<'_, [T; N]>) -> Init<'_, [T; N]>,
+    ) {
+        while self.remaining() >= N && N != 0 {
+            // SAFETY: there are at least `N` remaining slots
+            unsafe { self.init_chunk_unchecked(crate::func::InitFn::new(&mut f)) }
+        }
+    }
+
     /// finish the writer and get an initialized slice
     ///
     /// # Panics
@@ -80,17 +170,160 @@ impl<'a, T> SliceWriter<'a, T> {
     ///
     /// if the writer must be finished
     #[inline]
-    pub unsafe fn finish_unchecked(mut self) -> Init<'a, [T]> {
-        let uninit = core::mem::take(&mut self.uninit);
+    pub unsafe fn finish_unchecked(self) -> Init<'a, [T]> {
+        let ptr = core::ptr::slice_from_raw_parts_mut(self.start.as_ptr(), self.len());
         core::mem::forget(self);
         // SAFETY: a finished writer has initialized every element of the slice
-        unsafe { uninit.assume_init() }
+        unsafe { Init::from_raw(ptr) }
+    }
+
+    /// finish the writer and get the initialized prefix and the uninitialized remainder of the slice
+    ///
+    /// Unlike [`SliceWriter::finish`], this never panics
+    pub fn finish_partial(self) -> (Init<'a, [T]>, Uninit<'a, [T]>) {
+        let init = core::ptr::slice_from_raw_parts_mut(self.start.as_ptr(), self.initialized());
+        let uninit = core::ptr::slice_from_raw_parts_mut(self.slot(), self.remaining());
+        core::mem::forget(self);
+
+        // SAFETY: the first `initialized` elements are initialized, and the rest are
+        // disjoint and allocated for the rest of the lifetime `'a`
+        unsafe { (Init::from_raw(init), Uninit::from_raw(uninit)) }
     }
 
     /// Has the writer written to the entire slice
     #[inline(always)]
     pub fn is_finished(&self) -> bool {
-        self.remaining == 0
+        self.current == self.end
+    }
+
+    /// The length of the entire slice
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        Self::distance(self.start.as_ptr(), self.end)
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of elements which are still uninitialized
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        Self::distance(self.current, self.end)
+    }
+
+    /// The number of elements which were initialized
+    #[inline(always)]
+    pub fn initialized(&self) -> usize {
+        Self::distance(self.start.as_ptr(), self.current)
+    }
+
+    /// The initialized prefix of the slice
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `initialized` elements of the slice are initialized
+        unsafe { core::slice::from_raw_parts(self.start.as_ptr(), self.initialized()) }
+    }
+
+    /// The initialized prefix of the slice
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `initialized` elements of the slice are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.start.as_ptr(), self.initialized()) }
+    }
+
+    /// Clone each element of `slice` into the next `slice.len()` slots
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `slice.len()` remaining slots, this function will panic
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        assert!(slice.len() <= self.remaining());
+
+        for value in slice {
+            // SAFETY: there are at least `slice.len()` remaining slots
+            unsafe { self.init_unchecked(value.clone()) }
+        }
+    }
+
+    /// Initialize the remaining slots from the initializers yielded by `iter`,
+    /// until either the iterator or the writer is exhausted
+    ///
+    /// No items are taken from the iterator once the writer is finished
+    ///
+    /// Returns the number of slots which were initialized
+    pub fn extend_from_iter<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: Initialize<T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut count = 0;
+
+        while !self.is_finished() {
+            let Some(init) = iter.next() else { break };
+            // SAFETY: we're not finished yet
+            unsafe { self.init_unchecked(init) }
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Copy `slice` into the next `slice.len()` slots
+    ///
+    /// This is a single `memcpy`, unlike [`SliceWriter::extend_from_slice`]
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `slice.len()` remaining slots, this function will panic
+    pub fn copy_from_slice(&mut self, slice: &[T])
+    where
+        T: Copy,
+    {
+        assert!(slice.len() <= self.remaining());
+
+        // SAFETY: there are at least `slice.len()` remaining slots after current
+        // and the `slice` can't overlap the uninitialized portion of the writer
+        unsafe {
+            self.slot()
+                .copy_from_nonoverlapping(slice.as_ptr(), slice.len())
+        }
+
+        // SAFETY: there are at least `slice.len()` remaining slots, and they were initialized
+        unsafe { self.assume_next_init(slice.len()) }
+    }
+
+    /// A pointer to the next uninitialized slot
+    ///
+    /// The remaining [`SliceWriter::remaining`] slots after this pointer are uninitialized,
+    /// and may be initialized through this pointer, for example by FFI.
+    /// Then use [`SliceWriter::assume_next_init`] to mark them as initialized.
+    #[inline(always)]
+    pub fn next_ptr(&mut self) -> *mut T {
+        self.slot()
+    }
+
+    /// Mark the next `n` slots as initialized, without writing to them
+    ///
+    /// # Safety
+    ///
+    /// * there must be at least `n` remaining slots
+    /// * the next `n` slots must have been initialized to valid instances of `T` by other means
+    pub unsafe fn assume_next_init(&mut self, n: usize) {
+        debug_assert!(n <= self.remaining());
+
+        if Self::ZERO_SIZED {
+            self.current = self.current.wrapping_byte_add(n);
+        } else {
+            // SAFETY: there are at least `n` remaining slots
+            self.current = unsafe { self.current.add(n) };
+        }
     }
 
     /// Try to initialize the next slot
@@ -130,17 +363,84 @@ impl<'a, T> SliceWriter<'a, T> {
         // * the current pointer came from an uninit
         // * the writer isn't finished yet
         // therefore the pointer is still in bounds
-        let output = unsafe { crate::raw::try_init_in_place(init, self.current) };
+        let output = unsafe { crate::raw::try_init_in_place(init, self.slot()) };
 
         if output.is_ok() {
             // SAFETY: we aren't finished yet and the current slot was successfully initialized
-            self.current = unsafe { self.current.add(1) };
-            self.remaining -= 1;
+            unsafe { self.assume_next_init(1) }
+        }
+
+        output
+    }
+
+    /// Try to initialize the next `N` slots
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `N` remaining slots, this function will panic
+    pub fn try_write_chunk<const N: usize, I: TryInitialize<[T; N]>>(
+        &mut self,
+        init: I,
+    ) -> Result<(), I::Error> {
+        assert!(N <= self.remaining());
+        // SAFETY: there are at least `N` remaining slots
+        unsafe { self.try_init_chunk_unchecked(init) }
+    }
+
+    /// Initialize the next `N` slots
+    ///
+    /// # Panics
+    ///
+    /// if there are fewer than `N` remaining slots, this function will panic
+    pub fn write_chunk<const N: usize, I: Initialize<[T; N]>>(&mut self, init: I) {
+        assert!(N <= self.remaining());
+        // SAFETY: there are at least `N` remaining slots
+        unsafe { self.init_chunk_unchecked(init) }
+    }
+
+    /// Try to initialize the next `N` slots
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `N` remaining slots
+    pub unsafe fn try_init_chunk_unchecked<const N: usize, I: TryInitialize<[T; N]>>(
+        &mut self,
+        init: I,
+    ) -> Result<(), I::Error> {
+        debug_assert!(N <= self.remaining());
+
+        // SAFETY:
+        // * the current pointer came from an uninit
+        // * there are at least `N` remaining slots
+        // therefore the chunk is still in bounds
+        let output = unsafe { crate::raw::try_init_in_place(init, self.slot().cast()) };
+
+        if output.is_ok() {
+            // SAFETY: the next `N` slots were successfully initialized
+            unsafe { self.assume_next_init(N) }
         }
 
         output
     }
 
+    /// Initialize the next `N` slots
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `N` remaining slots
+    pub unsafe fn init_chunk_unchecked<const N: usize, I: Initialize<[T; N]>>(&mut self, init: I) {
+        debug_assert!(N <= self.remaining());
+
+        // SAFETY:
+        // * the current pointer came from an uninit
+        // * there are at least `N` remaining slots
+        // therefore the chunk is still in bounds
+        unsafe { crate::raw::init_in_place(init, self.slot().cast::<[T; N]>()) }
+
+        // SAFETY: the next `N` slots were successfully initialized
+        unsafe { self.assume_next_init(N) }
+    }
+
     /// Try to initialize the next slot
     ///
     /// # Panics
@@ -164,10 +464,82 @@ impl<'a, T> SliceWriter<'a, T> {
         // * the current pointer came from an uninit
         // * the writer isn't finished yet
         // therefore the pointer is still in bounds
-        unsafe { crate::raw::init_in_place(init, self.current) }
+        unsafe { crate::raw::init_in_place(init, self.slot()) }
 
         // SAFETY: we aren't finished yet and the current slot was successfully initialized
-        self.current = unsafe { self.current.add(1) };
-        self.remaining -= 1;
+        unsafe { self.assume_next_init(1) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_finish_partial() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 5];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let mut writer = SliceWriter::new(uninit);
+        writer.copy_from_slice(&[1, 2]);
+        assert_eq!(writer.extend_from_iter([3]), 1);
+        assert_eq!(writer.as_slice(), [1, 2, 3]);
+        assert_eq!(writer.remaining(), 2);
+
+        let (init, uninit) = writer.finish_partial();
+        assert_eq!(*init, [1, 2, 3]);
+        assert_eq!(uninit.len(), 2);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 7];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let mut writer = SliceWriter::new(uninit);
+        writer.for_each_chunk(|chunk: Uninit<[u32; 3]>| chunk.write([1, 2, 3]));
+        assert_eq!(writer.remaining(), 1);
+        let init = writer.for_each(|uninit| uninit.write(0));
+
+        assert_eq!(*init, [1, 2, 3, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let mut array = [core::mem::MaybeUninit::<()>::uninit(); 3];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let mut writer = SliceWriter::new(uninit);
+        assert_eq!(writer.len(), 3);
+        writer.write(());
+        assert_eq!(writer.initialized(), 1);
+        assert_eq!(writer.remaining(), 2);
+
+        let init = writer.for_each(|uninit| uninit.write(()));
+        assert_eq!(init.len(), 3);
+    }
+
+    #[test]
+    fn test_try_for_each_partial() {
+        let mut array = [core::mem::MaybeUninit::<u32>::uninit(); 4];
+        let uninit = Uninit::from_maybe_uninit_slice(&mut array);
+
+        let mut value = 0;
+        let result = SliceWriter::new(uninit).try_for_each_partial(|uninit| {
+            value += 1;
+            if value == 3 {
+                Err(value)
+            } else {
+                Ok(uninit.write(value))
+            }
+        });
+
+        let Err((err, writer)) = result else {
+            panic!("initialization should fail")
+        };
+        assert_eq!(err, 3);
+        let (init, uninit) = writer.finish_partial();
+        assert_eq!(*init, [1, 2]);
+        assert_eq!(uninit.len(), 2);
     }
 }
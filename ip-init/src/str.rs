@@ -0,0 +1,292 @@
+//! string initializers
+
+use core::ffi::CStr;
+
+use crate::{
+    layout::{CStrLayoutProvider, StrLayoutProvider},
+    traits::{SizeHint, TryInitialize},
+    Init, Uninit,
+};
+
+/// A string initializer which copies the given string into the slot
+#[derive(Debug, Clone, Copy)]
+pub struct StrInit<'a>(&'a str);
+
+impl<'a> StrInit<'a> {
+    /// Create a new string initializer
+    pub fn new(value: &'a str) -> Self {
+        Self(value)
+    }
+}
+
+/// The error for when a string doesn't have the same length as the slot it's initializing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrLengthError {
+    /// the length of the slot in bytes
+    pub slot_len: usize,
+    /// the length of the string in bytes
+    pub str_len: usize,
+}
+
+impl core::fmt::Display for StrLengthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "the string has {} bytes, but the slot has {} bytes",
+            self.str_len, self.slot_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrLengthError {}
+
+// SAFETY: the layout provider only depends on the length of the string, which doesn't change
+unsafe impl SizeHint<str> for StrInit<'_> {
+    type LayoutProvider = StrLayoutProvider;
+
+    fn layout_provider(&self) -> Self::LayoutProvider {
+        StrLayoutProvider(self.0.len())
+    }
+}
+
+impl TryInitialize<str> for StrInit<'_> {
+    type Error = StrLengthError;
+
+    fn try_init(self, ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        if ptr.len() == self.0.len() {
+            Ok(ptr.write_str(self.0))
+        } else {
+            Err(StrLengthError {
+                slot_len: ptr.len(),
+                str_len: self.0.len(),
+            })
+        }
+    }
+}
+
+/// A string initializer which encodes the chars of the given iterator into the slot
+///
+/// The encoded chars must have exactly the same length in bytes as the slot. If the iterator
+/// yields more chars than fit, this fails as soon as the slot overflows, and the error's
+/// `str_len` only counts the chars up to the first one which didn't fit.
+#[derive(Debug, Clone, Copy)]
+pub struct CharsInit<I>(I);
+
+impl<I> CharsInit<I> {
+    /// Create a new string initializer from an iterator of chars
+    pub fn new<C: IntoIterator<IntoIter = I>>(chars: C) -> Self {
+        Self(chars.into_iter())
+    }
+}
+
+impl<I: Iterator<Item = char>> TryInitialize<str> for CharsInit<I> {
+    type Error = StrLengthError;
+
+    fn try_init(self, mut ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        let bytes = ptr.as_mut_ptr() as *mut [u8];
+        let slot_len = bytes.len();
+        let bytes = bytes.cast::<u8>();
+        let mut str_len = 0;
+
+        for c in self.0 {
+            let mut buf = [0; 4];
+            let encoded = c.encode_utf8(&mut buf);
+
+            if encoded.len() > slot_len - str_len {
+                // stop early, so that unbounded iterators can't loop forever
+                return Err(StrLengthError {
+                    slot_len,
+                    str_len: str_len + encoded.len(),
+                });
+            }
+
+            // SAFETY: there are at least `encoded.len()` bytes left in the slot after `str_len`
+            unsafe {
+                bytes
+                    .add(str_len)
+                    .copy_from_nonoverlapping(encoded.as_ptr(), encoded.len())
+            }
+
+            str_len += encoded.len();
+        }
+
+        if str_len != slot_len {
+            return Err(StrLengthError { slot_len, str_len });
+        }
+
+        // SAFETY: the slot was filled with the UTF-8 encoding of a sequence of chars
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// A C string initializer which copies the given bytes into the slot, and appends a nul terminator
+///
+/// The slot must be exactly one byte longer than the bytes
+#[derive(Debug, Clone, Copy)]
+pub struct CStrInit<'a>(&'a [u8]);
+
+impl<'a> CStrInit<'a> {
+    /// Create a new C string initializer which copies `value` (without it's nul terminator)
+    pub fn new(value: &'a CStr) -> Self {
+        Self(value.to_bytes())
+    }
+
+    /// Create a new C string initializer from bytes without a nul terminator
+    ///
+    /// The bytes are checked for interior nul bytes when initializing
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// The length of the C string in bytes, including the nul terminator
+    pub fn len_with_nul(&self) -> usize {
+        self.0.len() + 1
+    }
+}
+
+/// The error for when a C string can't be initialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CStrInitError {
+    /// The slot doesn't have room for exactly the bytes and the nul terminator
+    LengthMismatch {
+        /// the length of the slot in bytes
+        slot_len: usize,
+        /// the length of the C string in bytes, including the nul terminator
+        len_with_nul: usize,
+    },
+    /// The bytes contained a nul byte before the end
+    InteriorNul {
+        /// the position of the first nul byte
+        position: usize,
+    },
+}
+
+impl core::fmt::Display for CStrInitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LengthMismatch {
+                slot_len,
+                len_with_nul,
+            } => write!(
+                f,
+                "the C string has {len_with_nul} bytes, but the slot has {slot_len} bytes"
+            ),
+            Self::InteriorNul { position } => {
+                write!(f, "found an interior nul byte at position {position}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CStrInitError {}
+
+// SAFETY: the layout provider only depends on the length of the string, which doesn't change
+unsafe impl SizeHint<CStr> for CStrInit<'_> {
+    type LayoutProvider = CStrLayoutProvider;
+
+    fn layout_provider(&self) -> Self::LayoutProvider {
+        CStrLayoutProvider(self.len_with_nul())
+    }
+}
+
+impl TryInitialize<CStr> for CStrInit<'_> {
+    type Error = CStrInitError;
+
+    fn try_init(self, mut ptr: Uninit<CStr>) -> Result<Init<CStr>, Self::Error> {
+        let bytes = ptr.as_mut_ptr() as *mut [u8];
+        let slot_len = bytes.len();
+
+        if slot_len != self.len_with_nul() {
+            return Err(CStrInitError::LengthMismatch {
+                slot_len,
+                len_with_nul: self.len_with_nul(),
+            });
+        }
+
+        if let Some(position) = self.0.iter().position(|&b| b == 0) {
+            return Err(CStrInitError::InteriorNul { position });
+        }
+
+        let bytes = bytes.cast::<u8>();
+        // SAFETY: the slot has room for the bytes and the nul terminator
+        unsafe {
+            bytes.copy_from_nonoverlapping(self.0.as_ptr(), self.0.len());
+            bytes.add(self.0.len()).write(0);
+        }
+
+        // SAFETY: the slot now contains the bytes, which have no nul bytes,
+        // followed by a nul terminator
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::layout::StrLayoutProvider;
+
+    #[test]
+    fn test_emplace_str() {
+        let boxed: alloc::boxed::Box<str> =
+            crate::boxed::emplace(StrLayoutProvider(5), StrInit::new("hello"));
+        assert_eq!(&*boxed, "hello");
+
+        let err = crate::boxed::try_emplace(StrLayoutProvider(3), StrInit::new("hello"));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_emplace_chars() {
+        let boxed: alloc::boxed::Box<str> =
+            crate::boxed::emplace(StrLayoutProvider(5), CharsInit::new("hé!?".chars()));
+        assert_eq!(&*boxed, "hé!?");
+
+        let err = crate::boxed::try_emplace::<str, _, _>(
+            StrLayoutProvider(2),
+            CharsInit::new(['a', 'é']),
+        );
+        assert!(matches!(
+            err,
+            Err(crate::boxed::AllocError::Init(StrLengthError {
+                slot_len: 2,
+                str_len: 3
+            }))
+        ));
+
+        let err = crate::boxed::try_emplace::<str, _, _>(
+            StrLayoutProvider(4),
+            CharsInit::new(core::iter::repeat('a')),
+        );
+        assert!(matches!(
+            err,
+            Err(crate::boxed::AllocError::Init(StrLengthError {
+                slot_len: 4,
+                str_len: 5
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_emplace_cstr() {
+        use crate::layout::CStrLayoutProvider;
+
+        let boxed: alloc::boxed::Box<CStr> =
+            crate::boxed::emplace(CStrLayoutProvider(6), CStrInit::from_bytes(b"hello"));
+        assert_eq!(&*boxed, c"hello");
+
+        let init = CStrInit::new(c"hi");
+        let boxed: alloc::boxed::Box<CStr> =
+            crate::boxed::emplace(CStrLayoutProvider(init.len_with_nul()), init);
+        assert_eq!(&*boxed, c"hi");
+
+        let err = crate::boxed::try_emplace(CStrLayoutProvider(4), CStrInit::from_bytes(b"a\0b"));
+        assert!(matches!(
+            err,
+            Err(crate::boxed::AllocError::Init(CStrInitError::InteriorNul {
+                position: 1
+            }))
+        ));
+    }
+}
@@ -14,26 +14,68 @@ use crate::{
 
 /// A layout provider takes a pair of an initializer and a type, and provides the layout that should be used for the type
 ///
+/// Most layout providers ignore the initializer, so they implement `LayoutProvider<T, I>` for all `I`.
+/// Layout providers which only see `()` as the initializer must compute the layout on their own.
+///
 /// # Safety
 ///
 /// * The layout of `T` must fit the allocation provided by `layout_for`
 /// * The `cast` function must return the same pointer that it was provided
-pub unsafe trait LayoutProvider<T: ?Sized> {
+/// * Given the same initializer, `layout_for` and `cast` must agree on the layout of `T`
+pub unsafe trait LayoutProvider<T: ?Sized, I: ?Sized = ()> {
     /// The layout of T, given the initializer
-    fn layout_for(&self) -> Result<Layout, LayoutError>;
+    fn layout_for(&self, init: &I) -> Result<Layout, LayoutError>;
 
-    /// Casts the pointer to `T`
-    fn cast(&self, ptr: *mut u8) -> *mut T;
+    /// Casts the pointer to `T`, given the initializer
+    fn cast(&self, init: &I, ptr: *mut u8) -> *mut T;
 
-    /// Casts the pointer to `T`
-    fn cast_nonnull(&self, ptr: NonNull<u8>) -> NonNull<T> {
+    /// Casts the pointer to `T`, given the initializer
+    fn cast_nonnull(&self, init: &I, ptr: NonNull<u8>) -> NonNull<T> {
         // SAFETY: the pointer is guaranteed to be non-null because
         // Self::cast will return the same pointer that it was passed in
         // and ptr is non-null
-        unsafe { NonNull::new_unchecked(self.cast(ptr.as_ptr())) }
+        unsafe { NonNull::new_unchecked(self.cast(init, ptr.as_ptr())) }
+    }
+}
+
+/// A type which has a default layout provider, this is implemented for all sized types
+///
+/// Dynamically sized types usually need extra information (such as a length) to compute
+/// their layout, so they must be given a layout provider explicitly
+pub trait HasLayoutProvider {
+    /// The default layout provider for this type
+    type LayoutProvider: LayoutProvider<Self>;
+
+    /// Get the default layout provider for this type
+    fn layout_provider() -> Self::LayoutProvider;
+}
+
+impl<T> HasLayoutProvider for T {
+    type LayoutProvider = crate::layout::SizedLayoutProvider;
+
+    #[inline]
+    fn layout_provider() -> Self::LayoutProvider {
+        crate::layout::SizedLayoutProvider
     }
 }
 
+/// An initializer which knows the layout of the value it will initialize,
+/// so the layout provider can be derived from the initializer instead of being
+/// provided separately (and possibly disagreeing with the initializer)
+///
+/// See [`InitLayoutProvider`](crate::layout::InitLayoutProvider)
+///
+/// # Safety
+///
+/// `layout_provider` must return equivalent layout providers every time it's called
+pub unsafe trait SizeHint<T: ?Sized> {
+    /// The layout provider for the value that will be initialized
+    type LayoutProvider: LayoutProvider<T>;
+
+    /// Get the layout provider which matches this initializer
+    fn layout_provider(&self) -> Self::LayoutProvider;
+}
+
 /// A trait to try to initialize a T
 ///
 /// * for infallible initialization, use [`Initialize`]
@@ -0,0 +1,691 @@
+//! growable vectors which initialize their elements in place, and extension traits
+//! for `Vec` and `VecDeque` which do the same
+
+use core::{
+    alloc::Layout,
+    fmt::Debug,
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use ::alloc::{collections::VecDeque, vec::Vec};
+
+use crate::{
+    layout::SliceLayoutProvider,
+    slice::SliceInit,
+    traits::{LayoutProvider, TryInitialize},
+    Uninit,
+};
+
+/// An extension trait for `Vec<T>` which initializes new elements in place in it's spare capacity
+pub trait VecEmplaceExt<T> {
+    /// Append an element to the back of the vector, and initialize it in place
+    fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug;
+
+    /// Append an element to the back of the vector, and attempt to initialize it in place
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>;
+
+    /// Append `len` elements to the back of the vector, and attempt to initialize them in place
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    fn extend_init<I>(&mut self, len: usize, init: I) -> Result<&mut [T], I::Error>
+    where
+        I: TryInitialize<[T]>;
+
+    /// Resize the vector to `new_len` elements
+    ///
+    /// If the vector shrinks, the excess elements are dropped. If it grows, each new element
+    /// is initialized in place by a clone of `init`.
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    fn resize_init<I>(&mut self, new_len: usize, init: I) -> Result<(), I::Error>
+    where
+        I: TryInitialize<T> + Clone;
+}
+
+impl<T> VecEmplaceExt<T> for Vec<T> {
+    fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_back(init) {
+            Ok(value) => value,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>,
+    {
+        self.reserve(1);
+        let len = self.len();
+
+        let slot = &mut self.spare_capacity_mut()[0];
+        let ptr = Uninit::from_maybe_uninit(slot).try_init(init)?.into_raw();
+
+        // SAFETY: the element after the end was just initialized, and it's ownership
+        // was passed to the vector by `into_raw`
+        unsafe {
+            self.set_len(len + 1);
+            Ok(&mut *ptr.as_ptr())
+        }
+    }
+
+    fn extend_init<I>(&mut self, len: usize, init: I) -> Result<&mut [T], I::Error>
+    where
+        I: TryInitialize<[T]>,
+    {
+        self.reserve(len);
+        let old_len = self.len();
+
+        let slots = &mut self.spare_capacity_mut()[..len];
+        let ptr = Uninit::from_maybe_uninit_slice(slots)
+            .try_init(init)?
+            .into_raw();
+
+        // SAFETY: the `len` elements after the end were just initialized, and their
+        // ownership was passed to the vector by `into_raw`
+        unsafe {
+            self.set_len(old_len + len);
+            Ok(&mut *ptr.as_ptr())
+        }
+    }
+
+    fn resize_init<I>(&mut self, new_len: usize, init: I) -> Result<(), I::Error>
+    where
+        I: TryInitialize<T> + Clone,
+    {
+        match new_len.checked_sub(self.len()) {
+            None => self.truncate(new_len),
+            Some(additional) => {
+                self.extend_init(additional, SliceInit::new(init))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An extension trait for `VecDeque<T>` which initializes new elements with initializers
+///
+/// `VecDeque` doesn't expose it's unused slots, so new elements can't be initialized in place.
+/// Instead the element is initialized on the stack, and then pushed onto the deque.
+pub trait VecDequeEmplaceExt<T> {
+    /// Append an element to the back of the deque, and initialize it in place
+    fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug;
+
+    /// Append an element to the back of the deque, and attempt to initialize it in place
+    ///
+    /// If initialization fails, then the deque has the same elements in the same order
+    fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>;
+
+    /// Prepend an element to the front of the deque, and initialize it in place
+    fn emplace_front<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug;
+
+    /// Prepend an element to the front of the deque, and attempt to initialize it in place
+    ///
+    /// If initialization fails, then the deque has the same elements in the same order
+    fn try_emplace_front<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>;
+}
+
+impl<T> VecDequeEmplaceExt<T> for VecDeque<T> {
+    fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_back(init) {
+            Ok(value) => value,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>,
+    {
+        let mut slot = MaybeUninit::uninit();
+        let value = Uninit::from_maybe_uninit(&mut slot)
+            .try_init(init)?
+            .into_inner();
+        self.push_back(value);
+
+        match self.back_mut() {
+            Some(value) => Ok(value),
+            None => unreachable!("an element was just appended"),
+        }
+    }
+
+    fn emplace_front<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_front(init) {
+            Ok(value) => value,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    fn try_emplace_front<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>,
+    {
+        let mut slot = MaybeUninit::uninit();
+        let value = Uninit::from_maybe_uninit(&mut slot)
+            .try_init(init)?
+            .into_inner();
+        self.push_front(value);
+
+        match self.front_mut() {
+            Some(value) => Ok(value),
+            None => unreachable!("an element was just prepended"),
+        }
+    }
+}
+
+/// A growable vector whose elements are initialized in place in it's spare capacity
+///
+/// The buffer is laid out by [`SliceLayoutProvider`], and new elements are initialized
+/// directly in the buffer, so they are never constructed on the stack and then moved in.
+/// This works well with large elements.
+pub struct InitVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    len: usize,
+    _ty: PhantomData<T>,
+}
+
+// SAFETY: `InitVec` owns it's elements, just like `Vec`
+unsafe impl<T: Send> Send for InitVec<T> {}
+// SAFETY: `InitVec` only gives out shared references to it's elements through a shared reference
+unsafe impl<T: Sync> Sync for InitVec<T> {}
+
+#[cold]
+#[inline(never)]
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+/// the layout of a buffer of `cap` elements
+fn buffer_layout<T>(cap: usize) -> Layout {
+    match LayoutProvider::<[T]>::layout_for(&SliceLayoutProvider(cap), &()) {
+        Ok(layout) => layout,
+        Err(_) => capacity_overflow(),
+    }
+}
+
+impl<T> InitVec<T> {
+    const IS_ZST: bool = core::mem::size_of::<T>() == 0;
+
+    /// Create a new empty vector, this doesn't allocate
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            cap: if Self::IS_ZST { usize::MAX } else { 0 },
+            len: 0,
+            _ty: PhantomData,
+        }
+    }
+
+    /// Create a new empty vector with room for at least `capacity` elements
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// The number of elements the vector can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Reserve room for at least `additional` more elements
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows
+    pub fn reserve(&mut self, additional: usize) {
+        let Some(required) = self.len.checked_add(additional) else {
+            capacity_overflow()
+        };
+
+        if required > self.cap {
+            self.grow(required.max(self.cap.saturating_mul(2)).max(4));
+        }
+    }
+
+    /// move the elements into a buffer of `cap` elements
+    fn grow(&mut self, cap: usize) {
+        let layout = buffer_layout::<T>(cap);
+
+        let ptr = if self.cap == 0 {
+            // SAFETY: `cap` is larger than the old capacity, and `T` isn't zero sized
+            // (otherwise the capacity would be `usize::MAX`), so the layout isn't zero sized
+            unsafe { alloc::alloc::alloc(layout) }
+        } else {
+            // SAFETY: the buffer was allocated with the layout of the old capacity, and the new
+            // layout has the same alignment and a size which fits in `isize`
+            unsafe {
+                alloc::alloc::realloc(
+                    self.ptr.as_ptr().cast(),
+                    buffer_layout::<T>(self.cap),
+                    layout.size(),
+                )
+            }
+        };
+
+        match NonNull::new(ptr) {
+            Some(ptr) => {
+                self.ptr = ptr.cast();
+                self.cap = cap;
+            }
+            None => alloc::alloc::handle_alloc_error(layout),
+        }
+    }
+
+    /// The uninitialized slots after the end of the vector
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // SAFETY: the buffer has room for `cap` elements, and the ones after `len` are unused
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len).cast(),
+                self.cap - self.len,
+            )
+        }
+    }
+
+    /// Append an element to the back of the vector, and initialize it in place
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows, or if initialization fails
+    pub fn emplace_back<I>(&mut self, init: I) -> &mut T
+    where
+        I: TryInitialize<T>,
+        I::Error: Debug,
+    {
+        match self.try_emplace_back(init) {
+            Ok(value) => value,
+            Err(err) => crate::init_failed(err),
+        }
+    }
+
+    /// Append an element to the back of the vector, and attempt to initialize it in place
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows
+    pub fn try_emplace_back<I>(&mut self, init: I) -> Result<&mut T, I::Error>
+    where
+        I: TryInitialize<T>,
+    {
+        self.reserve(1);
+
+        let slot = &mut self.spare_capacity_mut()[0];
+        let ptr = Uninit::from_maybe_uninit(slot).try_init(init)?.into_raw();
+        self.len += 1;
+
+        // SAFETY: the element after the end was just initialized, and it's ownership
+        // was passed to the vector by `into_raw`
+        Ok(unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Append `len` elements to the back of the vector, and attempt to initialize them in place
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows
+    pub fn extend_init<I>(&mut self, len: usize, init: I) -> Result<&mut [T], I::Error>
+    where
+        I: TryInitialize<[T]>,
+    {
+        self.reserve(len);
+
+        let slots = &mut self.spare_capacity_mut()[..len];
+        let ptr = Uninit::from_maybe_uninit_slice(slots)
+            .try_init(init)?
+            .into_raw();
+        self.len += len;
+
+        // SAFETY: the `len` elements after the end were just initialized, and their
+        // ownership was passed to the vector by `into_raw`
+        Ok(unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Resize the vector to `new_len` elements
+    ///
+    /// If the vector shrinks, the excess elements are dropped. If it grows, each new element
+    /// is initialized in place by a clone of `init`.
+    ///
+    /// If initialization fails, then the vector is unchanged (except possibly for it's capacity)
+    ///
+    /// # Panics
+    ///
+    /// If the capacity overflows
+    pub fn resize_init<I>(&mut self, new_len: usize, init: I) -> Result<(), I::Error>
+    where
+        I: TryInitialize<T> + Clone,
+    {
+        match new_len.checked_sub(self.len) {
+            None => self.truncate(new_len),
+            Some(additional) => {
+                self.extend_init(additional, SliceInit::new(init))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the last element from the vector
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // SAFETY: the element at `len` was initialized, and is no longer owned by the vector
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    /// Remove all elements from the vector
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Shorten the vector to `len` elements, and drop the rest
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail = core::ptr::slice_from_raw_parts_mut(
+            // SAFETY: `len` is less than the length, so it's in bounds of the buffer
+            unsafe { self.ptr.as_ptr().add(len) },
+            self.len - len,
+        );
+        // the length is updated first, so a panicking destructor can't cause a double drop
+        self.len = len;
+        // SAFETY: the tail was initialized, and is no longer owned by the vector
+        unsafe { tail.drop_in_place() }
+    }
+
+    /// View the elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// View the elements as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Convert into a `Vec<T>`, this doesn't reallocate
+    pub fn into_vec(self) -> Vec<T> {
+        let vec = ManuallyDrop::new(self);
+        // SAFETY: the buffer was allocated by the global allocator with the layout of `[T]`
+        // with `cap` elements (or is dangling and `cap` is zero or `T` is zero sized),
+        // and the first `len` elements are initialized
+        unsafe { Vec::from_raw_parts(vec.ptr.as_ptr(), vec.len, vec.cap) }
+    }
+}
+
+impl<T> Drop for InitVec<T> {
+    fn drop(&mut self) {
+        // SAFETY: the first `len` elements are initialized, and are owned by the vector
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+
+        if !Self::IS_ZST && self.cap != 0 {
+            // SAFETY: the buffer was allocated with the layout of `cap` elements
+            unsafe { alloc::alloc::dealloc(self.ptr.as_ptr().cast(), buffer_layout::<T>(self.cap)) }
+        }
+    }
+}
+
+impl<T> Default for InitVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for InitVec<T> {
+    fn clone(&self) -> Self {
+        self.as_slice().to_vec().into()
+    }
+}
+
+impl<T> From<Vec<T>> for InitVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+        Self {
+            // SAFETY: the pointer of a `Vec` is never null
+            ptr: unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) },
+            // the capacity of a `Vec` of zero sized types is `usize::MAX`, just like `InitVec`
+            cap: vec.capacity(),
+            len: vec.len(),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T> From<InitVec<T>> for Vec<T> {
+    fn from(vec: InitVec<T>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T> Deref for InitVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for InitVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: PartialEq> PartialEq for InitVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for InitVec<T> {}
+
+impl<T: Debug> Debug for InitVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_emplace_back() {
+        let mut vec = Vec::<[u32; 128]>::new();
+        vec.emplace_back(crate::array::ArrayFromFn::new(|i| i as u32));
+        vec.emplace_back(crate::zeroed::ZeroInit)[0] = 7;
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec[0][127], 127);
+        assert_eq!(vec[1][..2], [7, 0]);
+
+        let err = vec.try_emplace_back(crate::slice::SliceIterInit::new(0..1));
+        assert!(err.is_err());
+        assert_eq!(vec.len(), 2);
+
+        assert_eq!(vec.pop().map(|x| x[0]), Some(7));
+        assert_eq!(vec.len(), 1);
+    }
+
+    #[test]
+    fn test_init_vec() {
+        let mut vec = InitVec::<[u32; 128]>::new();
+        vec.emplace_back(crate::array::ArrayFromFn::new(|i| i as u32));
+        vec.emplace_back(crate::zeroed::ZeroInit)[0] = 7;
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec[0][127], 127);
+        assert_eq!(vec[1][..2], [7, 0]);
+
+        // the element is initialized in the slot after the end
+        let slot = vec.spare_capacity_mut().as_ptr() as *const [u32; 128];
+        let value = vec.emplace_back(crate::zeroed::ZeroInit) as *const [u32; 128];
+        assert_eq!(slot, value);
+
+        let err = vec.try_emplace_back(crate::slice::SliceIterInit::new(0..1));
+        assert!(err.is_err());
+        assert_eq!(vec.len(), 3);
+
+        assert_eq!(vec.pop().map(|x| x[0]), Some(0));
+        assert_eq!(vec.pop().map(|x| x[0]), Some(7));
+
+        let mut vec = InitVec::from(vec.into_vec());
+        vec.extend_init(2, crate::zeroed::ZeroInit).unwrap();
+        vec.resize_init(2, crate::zeroed::ZeroInit).unwrap();
+        assert_eq!(Vec::from(vec.clone()).len(), 2);
+        assert_eq!(vec[0][1], 1);
+    }
+
+    #[test]
+    fn test_init_vec_drop() {
+        let rc = ::alloc::rc::Rc::new(());
+        let mut vec = InitVec::with_capacity(1);
+        for _ in 0..10 {
+            vec.emplace_back(rc.clone());
+        }
+        assert!(vec.capacity() >= 10);
+        assert_eq!(::alloc::rc::Rc::strong_count(&rc), 11);
+
+        vec.truncate(4);
+        assert_eq!(::alloc::rc::Rc::strong_count(&rc), 5);
+        drop(vec);
+        assert_eq!(::alloc::rc::Rc::strong_count(&rc), 1);
+
+        let mut vec = InitVec::<()>::new();
+        vec.extend_init(3, crate::zeroed::ZeroInit).unwrap();
+        assert_eq!(vec.capacity(), usize::MAX);
+        assert_eq!(vec.into_vec(), [(), (), ()]);
+    }
+
+    #[test]
+    fn test_vec_emplace_ext() {
+        let mut vec = ::alloc::vec![1u32];
+        *vec.emplace_back(2) += 1;
+        let tail = vec
+            .extend_init(3, crate::slice::SliceIterInit::new(10..))
+            .unwrap();
+        assert_eq!(*tail, [10, 11, 12]);
+        assert_eq!(vec, [1, 3, 10, 11, 12]);
+
+        assert!(vec
+            .extend_init(3, crate::slice::SliceIterInit::new(0..2))
+            .is_err());
+        assert_eq!(vec.len(), 5);
+
+        vec.extend_init(1, crate::zeroed::ZeroInit).unwrap();
+        assert_eq!(vec, [1, 3, 10, 11, 12, 0]);
+    }
+
+    #[test]
+    fn test_resize_init() {
+        let mut vec = ::alloc::vec![[1u8; 4]];
+        vec.resize_init(3, crate::zeroed::ZeroInit).unwrap();
+        assert_eq!(vec, [[1; 4], [0; 4], [0; 4]]);
+
+        vec.resize_init(1, crate::zeroed::ZeroInit).unwrap();
+        assert_eq!(vec, [[1; 4]]);
+
+        let count = core::cell::Cell::new(0);
+        let init = crate::func::TryInitFn::new(|uninit: Uninit<[u8; 4]>| {
+            count.set(count.get() + 1);
+            if count.get() > 1 {
+                Err(())
+            } else {
+                Ok(uninit.write([2; 4]))
+            }
+        });
+        assert!(vec.resize_init(3, init).is_err());
+        assert_eq!(vec, [[1; 4]]);
+    }
+
+    #[test]
+    fn test_vec_deque_emplace_ext() {
+        let mut deque = VecDeque::<[u16; 64]>::with_capacity(4);
+        deque.push_back([1; 64]);
+        deque.push_back([2; 64]);
+        deque.pop_front();
+        deque.push_back([3; 64]);
+        deque.push_back([4; 64]);
+
+        deque.emplace_front(crate::zeroed::ZeroInit)[0] = 5;
+        deque.emplace_back(crate::array::ArrayFromFn::new(|i| i as u16));
+        deque.emplace_front(crate::zeroed::ZeroInit);
+
+        let firsts = deque.iter().map(|x| x[0]).collect::<Vec<_>>();
+        assert_eq!(firsts, [0, 5, 2, 3, 4, 0]);
+        assert_eq!(deque[5][63], 63);
+
+        let err = deque.try_emplace_front(crate::slice::SliceIterInit::new(0..1));
+        assert!(err.is_err());
+        assert_eq!(deque.len(), 6);
+        assert_eq!(deque[1][0], 5);
+    }
+
+    #[test]
+    fn test_vec_deque_emplace_wrapping() {
+        let mut deque = VecDeque::<u32>::with_capacity(4);
+        let capacity = deque.capacity();
+        deque.push_back(1);
+        let ptr = &deque[0] as *const u32;
+
+        // the new elements wrap around both ends of the buffer
+        for i in 0..capacity as u32 - 1 {
+            if i % 2 == 0 {
+                deque.emplace_front(10 + i);
+            } else {
+                deque.emplace_back(10 + i);
+            }
+        }
+
+        assert_eq!(deque.capacity(), capacity);
+        assert_eq!(deque.len(), capacity);
+        assert!(deque.iter().any(|x| core::ptr::eq(x, ptr)));
+
+        let (front, back) = deque.as_slices();
+        let items = front.iter().chain(back).copied().collect::<Vec<_>>();
+        assert_eq!(items[..2], [12, 10]);
+        assert_eq!(items[2..4], [1, 11]);
+    }
+}
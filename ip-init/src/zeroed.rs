@@ -0,0 +1,193 @@
+//! zero initialization
+//!
+//! this allows initializing types which are valid when all bytes are zero with a single `memset`
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    convert::Infallible,
+    marker::{PhantomData, PhantomPinned},
+    mem::MaybeUninit,
+    num::{self, Wrapping},
+    ptr::NonNull,
+};
+
+use crate::{
+    traits::{TryInitialize, TryPinInitialize},
+    Init, PinnedInit, PinnedUninit, Uninit,
+};
+
+#[cfg(feature = "derive")]
+pub use ip_init_derive::Zeroable;
+
+/// A type which is valid when all of it's bytes are zero
+///
+/// With the `derive` feature this can be derived for structs whose fields are all `Zeroable`,
+/// and for enums with a `C` or primitive repr whose variant with discriminant 0 only has
+/// `Zeroable` fields.
+///
+/// # Safety
+///
+/// The all zeros bit-pattern must be a valid instance of the type
+pub unsafe trait Zeroable {}
+
+macro_rules! zeroable {
+    ($($ty:ty),* $(,)?) => {$(
+        // SAFETY: the all zeros bit-pattern is valid for this type
+        unsafe impl Zeroable for $ty {}
+    )*};
+}
+
+zeroable! {
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    PhantomPinned,
+    Option<num::NonZeroU8>, Option<num::NonZeroU16>, Option<num::NonZeroU32>,
+    Option<num::NonZeroU64>, Option<num::NonZeroU128>, Option<num::NonZeroUsize>,
+    Option<num::NonZeroI8>, Option<num::NonZeroI16>, Option<num::NonZeroI32>,
+    Option<num::NonZeroI64>, Option<num::NonZeroI128>, Option<num::NonZeroIsize>,
+}
+
+// SAFETY: null is a valid raw pointer
+unsafe impl<T> Zeroable for *const T {}
+// SAFETY: null is a valid raw pointer
+unsafe impl<T> Zeroable for *mut T {}
+// SAFETY: `None` is represented by null
+unsafe impl<T> Zeroable for Option<NonNull<T>> {}
+// SAFETY: `None` is represented by null
+unsafe impl<T> Zeroable for Option<&T> {}
+// SAFETY: `None` is represented by null
+unsafe impl<T> Zeroable for Option<&mut T> {}
+// SAFETY: PhantomData has no bytes
+unsafe impl<T: ?Sized> Zeroable for PhantomData<T> {}
+// SAFETY: MaybeUninit<T> is valid for any bit-pattern
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+// SAFETY: Wrapping is `repr(transparent)`
+unsafe impl<T: Zeroable> Zeroable for Wrapping<T> {}
+// SAFETY: Cell is `repr(transparent)`
+unsafe impl<T: Zeroable> Zeroable for Cell<T> {}
+// SAFETY: UnsafeCell is `repr(transparent)`
+unsafe impl<T: Zeroable> Zeroable for UnsafeCell<T> {}
+// SAFETY: an array is valid if all of it's elements are valid
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
+macro_rules! zeroable_tuple {
+    ($($ty:ident)*) => {
+        // SAFETY: a tuple is valid if all of it's fields are valid
+        unsafe impl<$($ty: Zeroable),*> Zeroable for ($($ty,)*) {}
+    };
+}
+
+zeroable_tuple!(A);
+zeroable_tuple!(A B);
+zeroable_tuple!(A B C);
+zeroable_tuple!(A B C D);
+zeroable_tuple!(A B C D E);
+zeroable_tuple!(A B C D E F);
+
+/// An initializer which zeroes the value with a single `memset`
+///
+/// This can initialize any [`Zeroable`] type, and slices of [`Zeroable`] types
+#[derive(Debug, Clone, Copy)]
+pub struct ZeroInit;
+
+impl<T: Zeroable> TryInitialize<T> for ZeroInit {
+    type Error = Infallible;
+
+    #[inline]
+    fn try_init(self, mut ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        // SAFETY: the pointer is valid for writes
+        unsafe { ptr.as_mut_ptr().write_bytes(0, 1) }
+        // SAFETY: the all zeros bit-pattern is valid for `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Zeroable> TryInitialize<[T]> for ZeroInit {
+    type Error = Infallible;
+
+    #[inline]
+    fn try_init(self, mut ptr: Uninit<[T]>) -> Result<Init<[T]>, Self::Error> {
+        let len = ptr.len();
+        // SAFETY: the pointer is valid for writes of `len` elements
+        unsafe { ptr.as_mut_ptr().cast::<T>().write_bytes(0, len) }
+        // SAFETY: the all zeros bit-pattern is valid for `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Zeroable> TryPinInitialize<T> for ZeroInit {
+    type Error = Infallible;
+
+    #[inline]
+    fn try_pin_init(self, mut ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error> {
+        // SAFETY: the pointer is valid for writes
+        unsafe { ptr.as_mut_ptr().write_bytes(0, 1) }
+        // SAFETY: the all zeros bit-pattern is valid for `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Zeroable> TryPinInitialize<[T]> for ZeroInit {
+    type Error = Infallible;
+
+    #[inline]
+    fn try_pin_init(self, mut ptr: PinnedUninit<[T]>) -> Result<PinnedInit<[T]>, Self::Error> {
+        let len = ptr.len();
+        // SAFETY: the pointer is valid for writes of `len` elements
+        unsafe { ptr.as_mut_ptr().cast::<T>().write_bytes(0, len) }
+        // SAFETY: the all zeros bit-pattern is valid for `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::MaybeUninit;
+
+    use super::*;
+
+    #[test]
+    fn test_zero_init() {
+        let mut slots = [MaybeUninit::<(u32, Option<NonNull<u8>>, bool)>::uninit(); 3];
+        let slice = Uninit::from_maybe_uninit_slice(&mut slots).init(ZeroInit);
+        assert_eq!(slice.len(), 3);
+        assert!(slice.iter().all(|&value| value == (0, None, false)));
+
+        let mut slot = MaybeUninit::<(char, Option<&u8>, [f32; 2])>::uninit();
+        let value = Uninit::from_maybe_uninit(&mut slot).init(ZeroInit);
+        assert_eq!(*value, ('\0', None, [0.0; 2]));
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Zeroable)]
+    struct Header<T> {
+        len: usize,
+        next: *const Header<T>,
+        data: [T; 4],
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, Zeroable)]
+    #[repr(u8)]
+    enum State {
+        Running(u32) = 1,
+        Idle,
+        Stopped = 0,
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_zeroable() {
+        let mut slot = MaybeUninit::<Header<u16>>::uninit();
+        let header = Uninit::from_maybe_uninit(&mut slot).init(ZeroInit);
+        assert_eq!(header.len, 0);
+        assert!(header.next.is_null());
+        assert_eq!(header.data, [0; 4]);
+
+        let mut slot = MaybeUninit::<State>::uninit();
+        let state = Uninit::from_maybe_uninit(&mut slot).init(ZeroInit);
+        assert_eq!(*state, State::Stopped);
+    }
+}
@@ -2,8 +2,11 @@ use std::{marker::PhantomPinned, pin::Pin};
 
 use ip_init::{pin_ptr::PinnedUninit, traits::PinInitialize, PinnedInit};
 
+#[derive(ip_init::project::ProjectUninit)]
+#[project_uninit(unsafe_pin_project)]
 #[pin_project::pin_project]
 pub struct SelfRef {
+    #[pin]
     first: i32,
     second: i32,
 
@@ -16,20 +19,18 @@ unsafe impl Sync for SelfRef {}
 
 impl SelfRef {
     pub fn init(value: i32) -> impl PinInitialize<Self> {
-        ip_init::func::PinInitFn::new(move |uninit| Self::new_in(uninit, value))
-    }
-
-    pub fn new_in(mut uninit: PinnedUninit<Self>, value: i32) -> PinnedInit<Self> {
-        let current = ip_init::project_pin!(Self, uninit, first).as_mut_ptr();
-
-        uninit.write(Self {
+        ip_init::pin_init!(Self {
             first: value,
             second: 0,
-            current,
+            current: first,
             _pin: PhantomPinned,
         })
     }
 
+    pub fn new_in(uninit: PinnedUninit<Self>, value: i32) -> PinnedInit<Self> {
+        uninit.init(Self::init(value))
+    }
+
     pub fn new(value: i32) -> Pin<Box<Self>> {
         ip_init::boxed::emplace_pin(ip_init::layout::SizedLayoutProvider, Self::init(value))
     }
@@ -53,7 +54,7 @@ impl SelfRef {
 
     pub fn set_first(self: Pin<&mut Self>) {
         let this = self.project();
-        *this.current = &mut *this.first;
+        *this.current = this.first.get_mut();
     }
 
     pub fn set_second(self: Pin<&mut Self>) {